@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    snark::varuna::{ahp::AHPForR1CS, TestCircuit, VarunaHidingMode, VarunaSNARK},
+    AlgebraicSponge,
+    SNARK,
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+use snarkvm_utilities::TestRng;
+
+use criterion::{BenchmarkId, Criterion};
+use std::{collections::BTreeMap, time::Duration};
+
+type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+type FS = PoseidonSponge<Fq, 2, 1>;
+
+/// Benchmarks proving and verifying a batch of several circuits with several instances each.
+/// The linear combinations of such a batch share many polynomials at the same query points,
+/// so the cost of `construct_linear_combinations` grows with the amount of overlap.
+fn overlapping_linear_combinations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("overlapping_linear_combinations");
+    let rng = &mut TestRng::default();
+
+    let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(1000, 1000, 1000).unwrap();
+    let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+    let fs_parameters = FS::sample_parameters();
+
+    let circuit_batch_size = 4;
+    for instance_batch_size in [1, 4, 16] {
+        let mut pks = Vec::with_capacity(circuit_batch_size);
+        let mut vks = Vec::with_capacity(circuit_batch_size);
+        let mut all_circuits = Vec::with_capacity(circuit_batch_size);
+        let mut all_inputs = Vec::with_capacity(circuit_batch_size);
+        for i in 0..circuit_batch_size {
+            let mut circuits = Vec::with_capacity(instance_batch_size);
+            let mut inputs = Vec::with_capacity(instance_batch_size);
+            for _ in 0..instance_batch_size {
+                let (circuit, public_inputs) = TestCircuit::gen_rand(1 + i, 100 + i, 25 + i, rng);
+                circuits.push(circuit);
+                inputs.push(public_inputs);
+            }
+            let (pk, vk) = VarunaInst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+            pks.push(pk);
+            vks.push(vk);
+            all_circuits.push(circuits);
+            all_inputs.push(inputs);
+        }
+
+        let mut keys_to_constraints = BTreeMap::new();
+        let mut keys_to_inputs = BTreeMap::new();
+        for i in 0..circuit_batch_size {
+            keys_to_constraints.insert(&pks[i], all_circuits[i].as_slice());
+            keys_to_inputs.insert(&vks[i], all_inputs[i].as_slice());
+        }
+
+        group.bench_function(BenchmarkId::new("prove", instance_batch_size), |b| {
+            b.iter(|| VarunaInst::prove_batch(universal_prover, &fs_parameters, &keys_to_constraints, rng).unwrap())
+        });
+
+        let proof = VarunaInst::prove_batch(universal_prover, &fs_parameters, &keys_to_constraints, rng).unwrap();
+        group.bench_function(BenchmarkId::new("verify", instance_batch_size), |b| {
+            b.iter(|| {
+                let verification =
+                    VarunaInst::verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &proof).unwrap();
+                assert!(verification);
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = linear_combinations;
+    config = Criterion::default().measurement_time(Duration::from_secs(10));
+    targets = overlapping_linear_combinations,
+}
+
+criterion_main!(linear_combinations);
@@ -24,8 +24,8 @@ use snarkvm_algorithms::{
 use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
 use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, TestRng};
 
-use criterion::Criterion;
-use std::{collections::BTreeMap, time::Duration};
+use criterion::{BatchSize, Criterion};
+use std::{collections::BTreeMap, ops::Deref, time::Duration};
 
 type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
 type FS = PoseidonSponge<Fq, 2, 1>;
@@ -120,6 +120,56 @@ fn snark_batch_prove(c: &mut Criterion) {
     });
 }
 
+fn snark_prover_second_round(c: &mut Criterion) {
+    let mut group = c.benchmark_group("snark_prover_second_round");
+    let rng = &mut TestRng::default();
+
+    let num_constraints = 100;
+    let num_variables = 25;
+    let mul_depth = 1;
+
+    let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(1000, 1000, 1000).unwrap();
+    let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+    let fs_parameters = FS::sample_parameters();
+
+    let (circuit, _) = TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng);
+    let (pk, _) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+    let circuit_id = pk.circuit_verifying_key.id;
+
+    for batch_size in [1, 8, 64, 256] {
+        let circuits = vec![circuit.clone(); batch_size];
+        let keys_to_constraints = BTreeMap::from([(pk.circuit.deref(), circuits.as_slice())]);
+        let batch_sizes = BTreeMap::from([(circuit_id, batch_size)]);
+        let circuit_infos = BTreeMap::from([(circuit_id, &pk.circuit_verifying_key.circuit_info)]);
+
+        group.bench_function(format!("{batch_size}"), |b| {
+            b.iter_batched(
+                || {
+                    // Run the rounds preceding the second round, outside of the measurement.
+                    let state = AHPForR1CS::<_, VarunaHidingMode>::init_prover(&keys_to_constraints, rng).unwrap();
+                    let state = AHPForR1CS::<_, VarunaHidingMode>::prover_first_round(state, rng).unwrap();
+                    let (message, _) = AHPForR1CS::<_, VarunaHidingMode>::verifier_first_round(
+                        &batch_sizes,
+                        &circuit_infos,
+                        state.max_constraint_domain(),
+                        state.max_variable_domain(),
+                        state.max_non_zero_domain(),
+                        &mut FS::new_with_parameters(&fs_parameters),
+                    )
+                    .unwrap();
+                    (message, state)
+                },
+                |(message, state)| {
+                    AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&message, state, &mut TestRng::default())
+                        .unwrap()
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
 fn snark_verify(c: &mut Criterion) {
     let rng = &mut TestRng::default();
 
@@ -316,7 +366,7 @@ fn snark_certificate_verify(c: &mut Criterion) {
 criterion_group! {
     name = varuna_snark;
     config = Criterion::default().measurement_time(Duration::from_secs(10));
-    targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_verify, snark_batch_prove, snark_batch_verify, snark_vk_serialize, snark_vk_deserialize, snark_certificate_prove, snark_certificate_verify,
+    targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_prover_second_round, snark_verify, snark_batch_prove, snark_batch_verify, snark_vk_serialize, snark_vk_deserialize, snark_certificate_prove, snark_certificate_verify,
 }
 
 criterion_main!(varuna_snark);
@@ -46,6 +46,9 @@ pub enum SNARKError {
 
     #[error("Circuit not found")]
     CircuitNotFound,
+
+    #[error("Expected {expected} oracles in the {round} round of the proof, found {found}")]
+    OracleCountMismatch { round: &'static str, expected: usize, found: usize },
 }
 
 impl From<AHPError> for SNARKError {
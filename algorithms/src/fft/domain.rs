@@ -145,6 +145,17 @@ impl<F: FftField> EvaluationDomain<F> {
         })
     }
 
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, or return an error naming the field's maximum supported size.
+    pub fn new_checked(num_coeffs: usize) -> Result<Self> {
+        Self::new(num_coeffs).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot construct an evaluation domain for {num_coeffs} coefficients (the maximum size is 2^{})",
+                F::FftParameters::TWO_ADICITY
+            )
+        })
+    }
+
     /// Return the size of a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
     pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
@@ -885,6 +896,11 @@ pub struct FFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> FFTPrecomputation<F> {
+    /// Returns the domain that this precomputation was built for.
+    pub fn domain(&self) -> &EvaluationDomain<F> {
+        &self.domain
+    }
+
     pub fn to_ifft_precomputation(&self) -> IFFTPrecomputation<F> {
         let mut inverse_roots = self.roots.clone();
         snarkvm_fields::batch_inversion(&mut inverse_roots);
@@ -915,6 +931,11 @@ pub struct IFFTPrecomputation<F: FftField> {
 }
 
 impl<F: FftField> IFFTPrecomputation<F> {
+    /// Returns the domain that this precomputation was built for.
+    pub fn domain(&self) -> &EvaluationDomain<F> {
+        &self.domain
+    }
+
     pub fn precomputation_for_subdomain<'a>(&'a self, domain: &EvaluationDomain<F>) -> Option<Cow<'a, Self>> {
         if domain.size() == 1 {
             return Some(Cow::Owned(Self { inverse_roots: vec![], domain: *domain }));
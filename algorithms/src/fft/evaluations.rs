@@ -44,6 +44,17 @@ impl<F: PrimeField> Evaluations<F> {
         Self { evaluations, domain }
     }
 
+    /// Construct `Self` from evaluations and a domain, ensuring there is exactly one evaluation per domain element.
+    pub fn try_from_vec_and_domain(evaluations: Vec<F>, domain: EvaluationDomain<F>) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            evaluations.len() == domain.size(),
+            "Found {} evaluations for a domain of size {}",
+            evaluations.len(),
+            domain.size()
+        );
+        Ok(Self { evaluations, domain })
+    }
+
     /// Interpolate a polynomial from a list of evaluations
     pub fn interpolate_by_ref(&self) -> DensePolynomial<F> {
         DensePolynomial::from_coefficients_vec(self.domain.ifft(&self.evaluations))
@@ -89,6 +100,17 @@ impl<F: PrimeField> Evaluations<F> {
     pub fn evaluate_with_coeffs(&self, lagrange_coefficients_at_point: &[F]) -> F {
         cfg_iter!(self.evaluations).zip_eq(lagrange_coefficients_at_point).map(|(a, b)| *a * b).sum()
     }
+
+    /// Multiplies each evaluation of `self` by `scalar`, in place.
+    pub fn scale_in_place(&mut self, scalar: F) {
+        cfg_iter_mut!(self.evaluations).for_each(|e| *e *= scalar);
+    }
+
+    /// Returns the evaluations of `self` multiplied by `scalar`, over the same domain.
+    pub fn scale(&self, scalar: F) -> Evaluations<F> {
+        let evaluations = cfg_iter!(self.evaluations).map(|e| *e * scalar).collect();
+        Self { evaluations, domain: self.domain }
+    }
 }
 
 impl<F: PrimeField> std::ops::Index<usize> for Evaluations<F> {
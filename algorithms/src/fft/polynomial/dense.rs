@@ -17,7 +17,7 @@
 use super::PolyMultiplier;
 use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*};
+use snarkvm_utilities::{cfg_iter_mut, error, serialize::*};
 
 use anyhow::Result;
 use num_traits::CheckedDiv;
@@ -130,6 +130,33 @@ impl<F: Field> DensePolynomial<F> {
         &self.coeffs
     }
 
+    /// Returns `true` if `self` and `other` are the same polynomial, ignoring any trailing zero coefficients.
+    pub fn eq_canonical(&self, other: &Self) -> bool {
+        let trim = |coeffs: &[F]| coeffs.len() - coeffs.iter().rev().take_while(|coeff| coeff.is_zero()).count();
+        self.coeffs[..trim(&self.coeffs)] == other.coeffs[..trim(&other.coeffs)]
+    }
+
+    /// Writes the coefficients of `self` to `writer` in little-endian order, one at a time.
+    /// The output is the number of coefficients as a `u64`, followed by each coefficient.
+    pub fn write_coeffs_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        (self.coeffs.len() as u64).write_le(&mut writer)?;
+        for coeff in &self.coeffs {
+            coeff.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a polynomial written by `write_coeffs_le` from `reader`.
+    pub fn read_coeffs_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        let num_coeffs = usize::try_from(u64::read_le(&mut reader)?).map_err(|_| error("Too many coefficients"))?;
+        // Do not trust the length prefix for the initial allocation.
+        let mut coeffs = Vec::with_capacity(num_coeffs.min(1 << 16));
+        for _ in 0..num_coeffs {
+            coeffs.push(F::read_le(&mut reader)?);
+        }
+        Ok(Self::from_coefficients_vec(coeffs))
+    }
+
     /// Perform a naive n^2 multiplication of `self` by `other`.
     #[cfg(test)]
     fn naive_mul(&self, other: &Self) -> Self {
@@ -240,6 +267,17 @@ impl<'a, F: Field> AddAssign<&'a DensePolynomial<F>> for DensePolynomial<F> {
     }
 }
 
+impl<F: Field> AddAssign<DensePolynomial<F>> for DensePolynomial<F> {
+    /// Adds `other` to `self`, reusing the allocation of `other` if `self` is zero.
+    fn add_assign(&mut self, other: DensePolynomial<F>) {
+        if self.is_zero() {
+            *self = other;
+        } else {
+            *self += &other;
+        }
+    }
+}
+
 impl<'a, F: Field> AddAssign<&'a Polynomial<'a, F>> for DensePolynomial<F> {
     fn add_assign(&mut self, other: &'a Polynomial<F>) {
         match other {
@@ -527,6 +565,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn write_and_read_coeffs_le() {
+        let rng = &mut TestRng::default();
+        for degree in 0..70 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let mut bytes = Vec::new();
+            p.write_coeffs_le(&mut bytes).unwrap();
+            assert_eq!(DensePolynomial::read_coeffs_le(&bytes[..]).unwrap(), p);
+
+            // Ensure truncated input is rejected.
+            assert!(DensePolynomial::<Fr>::read_coeffs_le(&bytes[..bytes.len() - 1]).is_err());
+        }
+
+        // Ensure the zero polynomial round-trips.
+        let mut bytes = Vec::new();
+        DensePolynomial::<Fr>::zero().write_coeffs_le(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 8);
+        assert!(DensePolynomial::<Fr>::read_coeffs_le(&bytes[..]).unwrap().is_zero());
+    }
+
     #[test]
     fn add_polynomials() {
         let rng = &mut TestRng::default();
@@ -541,6 +599,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn add_assign_zero_polynomials() {
+        let rng = &mut TestRng::default();
+        for degree in 0..70 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+
+            // Adding to zero yields the other operand.
+            let mut sum = DensePolynomial::zero();
+            sum += p.clone();
+            assert_eq!(sum, p);
+
+            // Adding zero yields the original operand.
+            let mut sum = p.clone();
+            sum += DensePolynomial::zero();
+            assert_eq!(sum, p);
+
+            // Adding two non-zero operands matches the by-reference addition.
+            let q = DensePolynomial::<Fr>::rand(degree, rng);
+            let mut sum = p.clone();
+            sum += q.clone();
+            assert_eq!(sum, &p + &q);
+        }
+    }
+
     #[test]
     fn add_polynomials_with_mul() {
         let rng = &mut TestRng::default();
@@ -703,6 +785,87 @@ mod tests {
         // Note PolyMultiplier doesn't support evaluations with no polynomials
     }
 
+    #[test]
+    fn mul_polynomials_with_evaluations() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..10 {
+            let a = DensePolynomial::<Fr>::rand(64, rng);
+            let b = DensePolynomial::<Fr>::rand(32, rng);
+            let c = DensePolynomial::<Fr>::rand(16, rng);
+
+            // Evaluate `c` over its own domain, which does not match the domain of the product.
+            let c_domain = EvaluationDomain::new(c.degree() + 1).unwrap();
+            let c_evals = c.evaluate_over_domain_by_ref(c_domain);
+
+            let mut expected = PolyMultiplier::new();
+            expected.add_polynomial_ref(&a, "a");
+            expected.add_polynomial_ref(&b, "b");
+            expected.add_polynomial(c_evals.interpolate_by_ref(), "c");
+            let expected = expected.multiply().unwrap();
+            assert_eq!(expected, a.naive_mul(&b).naive_mul(&c));
+
+            // Ensure mismatched evaluations are interpolated.
+            let mut multiplier = PolyMultiplier::new();
+            multiplier.add_polynomial_ref(&a, "a");
+            multiplier.add_polynomial_ref(&b, "b");
+            multiplier.add_evaluation(c_evals, "c");
+            assert_eq!(multiplier.multiply().unwrap(), expected);
+
+            // Ensure evaluations over the domain of the product are used directly.
+            let ab_domain = EvaluationDomain::new(a.degree() + b.degree() + 2).unwrap();
+            let mut multiplier = PolyMultiplier::new();
+            multiplier.add_polynomial_ref(&a, "a");
+            multiplier.add_evaluation(b.evaluate_over_domain_by_ref(ab_domain), "b");
+            assert_eq!(multiplier.multiply().unwrap(), a.naive_mul(&b));
+        }
+    }
+
+    #[test]
+    fn mul_polynomials_predictions() {
+        let rng = &mut TestRng::default();
+
+        // Ensure an empty multiplier needs no domain.
+        let multiplier = PolyMultiplier::<Fr>::new();
+        assert_eq!(multiplier.predicted_output_degree(), 0);
+        assert_eq!(multiplier.predicted_domain_size(), Some(0));
+
+        for _ in 0..10 {
+            let a = DensePolynomial::<Fr>::rand(64, rng);
+            let b = DensePolynomial::<Fr>::rand(32, rng);
+            let c = DensePolynomial::<Fr>::rand(16, rng);
+            let ab_domain = EvaluationDomain::new(a.degree() + b.degree() + 2).unwrap();
+
+            // Ensure the predictions are exact for polynomials.
+            let mut multiplier = PolyMultiplier::new();
+            multiplier.add_polynomial_ref(&a, "a");
+            multiplier.add_polynomial_ref(&b, "b");
+            assert_eq!(multiplier.predicted_output_degree(), a.degree() + b.degree());
+            assert_eq!(multiplier.predicted_domain_size(), Some(ab_domain.size()));
+            let product = multiplier.multiply().unwrap();
+            assert_eq!(product.degree(), a.degree() + b.degree());
+
+            // Ensure evaluations over the chosen domain do not grow it.
+            let mut multiplier = PolyMultiplier::new();
+            multiplier.add_polynomial_ref(&a, "a");
+            multiplier.add_evaluation(b.evaluate_over_domain_by_ref(ab_domain), "b");
+            assert_eq!(multiplier.predicted_output_degree(), a.degree() + ab_domain.size() - 1);
+            assert_eq!(multiplier.predicted_domain_size(), Some(ab_domain.size()));
+
+            // Ensure mismatched evaluations are bounded by the size of their domain.
+            let c_domain = EvaluationDomain::new(c.degree() + 1).unwrap();
+            let mut multiplier = PolyMultiplier::new();
+            multiplier.add_polynomial_ref(&a, "a");
+            multiplier.add_polynomial_ref(&b, "b");
+            multiplier.add_evaluation(c.evaluate_over_domain_by_ref(c_domain), "c");
+            let predicted_degree = multiplier.predicted_output_degree();
+            assert_eq!(predicted_degree, a.degree() + b.degree() + c_domain.size() - 1);
+            let expected_domain = EvaluationDomain::<Fr>::new(a.degree() + b.degree() + 2 + c_domain.size()).unwrap();
+            assert_eq!(multiplier.predicted_domain_size(), Some(expected_domain.size()));
+            assert!(multiplier.multiply().unwrap().degree() <= predicted_degree);
+        }
+    }
+
     #[test]
     fn mul_by_vanishing_poly() {
         let rng = &mut TestRng::default();
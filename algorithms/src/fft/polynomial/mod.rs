@@ -20,6 +20,7 @@ use snarkvm_utilities::{cfg_iter_mut, serialize::*, SerializationError};
 use Polynomial::*;
 
 use anyhow::{ensure, Result};
+use itertools::Either;
 use std::{borrow::Cow, convert::TryInto};
 
 #[cfg(not(feature = "serial"))]
@@ -210,13 +211,21 @@ impl<'a, F: Field> Polynomial<'a, F> {
         }
     }
 
-    pub fn coeffs(&'a self) -> Box<dyn Iterator<Item = (usize, &'a F)> + 'a> {
+    /// Returns an iterator over the `(index, coefficient)` pairs of `self`, in increasing order of index.
+    /// A dense polynomial yields every coefficient, including zeros, while a sparse polynomial only yields its terms.
+    pub fn coeffs(&'a self) -> impl Iterator<Item = (usize, &'a F)> + 'a {
         match self {
-            Sparse(p) => Box::new(p.coeffs().map(|(c, f)| (*c, f))),
-            Dense(p) => Box::new(p.coeffs.iter().enumerate()),
+            Sparse(p) => Either::Left(p.coeffs().map(|(c, f)| (*c, f))),
+            Dense(p) => Either::Right(p.coeffs.iter().enumerate()),
         }
     }
 
+    /// Returns an iterator over the `(index, coefficient)` pairs of `self` with a nonzero coefficient,
+    /// in increasing order of index.
+    pub fn nonzero_coeffs(&'a self) -> impl Iterator<Item = (usize, &'a F)> + 'a {
+        self.coeffs().filter(|(_, coeff)| !coeff.is_zero())
+    }
+
     /// Divide self by another (sparse or dense) polynomial, and returns the quotient and remainder.
     pub fn divide_with_q_and_r(&self, divisor: &Self) -> Result<(DensePolynomial<F>, DensePolynomial<F>)> {
         ensure!(!divisor.is_zero(), "Dividing by zero polynomial is undefined");
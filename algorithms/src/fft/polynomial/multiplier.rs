@@ -60,74 +60,122 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
         self.evaluations.push((label.to_string(), Cow::Borrowed(evals)))
     }
 
+    /// Returns the degree of the product of all polynomials stored in `self`, without multiplying them.
+    ///
+    /// Each evaluation counts with the maximum degree over its domain, so the prediction is exact
+    /// for polynomials, and an upper bound when evaluations are present.
+    pub fn predicted_output_degree(&self) -> usize {
+        let polynomials = self.polynomials.iter().map(|(_, p)| p.degree());
+        let evaluations = self.evaluations.iter().map(|(_, e)| e.domain().size() - 1);
+        polynomials.chain(evaluations).sum()
+    }
+
+    /// Returns the size of the domain `multiply` chooses for the product, without multiplying.
+    ///
+    /// Evaluations that are not over the chosen domain are interpolated by `multiply`, and count with
+    /// the maximum degree over their domain, so the prediction is an upper bound in that case.
+    /// Returns `Some(0)` if `self` is empty, as no domain is needed,
+    /// and `None` if `F` does not contain a sufficiently large subgroup, in which case `multiply` fails.
+    pub fn predicted_domain_size(&self) -> Option<usize> {
+        if self.polynomials.is_empty() && self.evaluations.is_empty() {
+            return Some(0);
+        }
+        let mut num_coeffs = self.polynomials.iter().map(|(_, p)| p.degree() + 1).sum::<usize>();
+        let mut pending = self.evaluations.iter().map(|(_, e)| e.domain().size()).collect::<Vec<_>>();
+        loop {
+            let size = EvaluationDomain::<F>::compute_size_of_domain(num_coeffs)?;
+            // Mirror `choose_domain`, where growing the domain may invalidate previously matching evaluations.
+            let (matching, mismatched): (Vec<_>, Vec<_>) = pending.into_iter().partition(|&s| s == size);
+            if mismatched.is_empty() {
+                return Some(size);
+            }
+            num_coeffs = mismatched.into_iter().try_fold(num_coeffs, |sum, s| sum.checked_add(s))?;
+            pending = matching;
+        }
+    }
+
     /// Multiplies all polynomials stored in `self`.
     ///
-    /// Returns `None` if any of the stored evaluations are over a domain that's
-    /// insufficiently large to interpolate the product, or if `F` does not contain
-    /// a sufficiently large subgroup for interpolation.
+    /// Evaluations over the domain chosen for the product are used directly.
+    /// Any other evaluations are first interpolated, and multiplied as polynomials.
+    ///
+    /// Returns `None` if `F` does not contain a sufficiently large subgroup for interpolation.
     #[allow(unused_mut)]
     pub fn multiply(mut self) -> Option<DensePolynomial<F>> {
         if self.polynomials.is_empty() && self.evaluations.is_empty() {
             Some(DensePolynomial::zero())
         } else {
-            let degree = self.polynomials.iter().map(|(_, p)| p.degree() + 1).sum::<usize>();
-            let domain = EvaluationDomain::new(degree)?;
-            if self.evaluations.iter().any(|(_, e)| e.domain() != domain) {
-                None
-            } else {
-                #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-                {
-                    let mut poly_slices = Vec::new();
-                    for (_, p) in &self.polynomials {
-                        poly_slices.push(p.coeffs().to_vec());
-                    }
-                    let mut eval_slices = Vec::new();
-                    for (_, e) in &self.evaluations {
-                        eval_slices.push(e.evaluations().to_vec());
-                    }
-
-                    let gpu_result_vec =
-                        snarkvm_algorithms_cuda::polymul(domain.size(), &poly_slices, &eval_slices, &F::zero());
-                    if let Ok(result) = gpu_result_vec {
-                        return Some(DensePolynomial::from_coefficients_vec(result));
-                    }
-                }
-
-                if self.fft_precomputation.is_none() {
-                    self.fft_precomputation = Some(Cow::Owned(domain.precompute_fft()));
-                }
-                if self.ifft_precomputation.is_none() {
-                    self.ifft_precomputation =
-                        Some(Cow::Owned(self.fft_precomputation.as_ref().unwrap().to_ifft_precomputation()));
+            let domain = self.choose_domain()?;
+            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+            {
+                let mut poly_slices = Vec::new();
+                for (_, p) in &self.polynomials {
+                    poly_slices.push(p.coeffs().to_vec());
                 }
-                let fft_pc = &self.fft_precomputation.unwrap();
-                let ifft_pc = &self.ifft_precomputation.unwrap();
-                let mut pool = ExecutionPool::with_capacity(self.polynomials.len() + self.evaluations.len());
-                for (_, p) in self.polynomials {
-                    pool.add_job(move || {
-                        let mut p = p.into_owned().coeffs;
-                        p.resize(domain.size(), F::zero());
-                        domain.out_order_fft_in_place_with_pc(&mut p, fft_pc);
-                        p
-                    })
+                let mut eval_slices = Vec::new();
+                for (_, e) in &self.evaluations {
+                    eval_slices.push(e.evaluations().to_vec());
                 }
-                for (_, e) in self.evaluations {
-                    pool.add_job(move || {
-                        let mut e = e.into_owned().evaluations;
-                        e.resize(domain.size(), F::zero());
-                        crate::fft::domain::derange(&mut e);
-                        e
-                    })
+
+                let gpu_result_vec =
+                    snarkvm_algorithms_cuda::polymul(domain.size(), &poly_slices, &eval_slices, &F::zero());
+                if let Ok(result) = gpu_result_vec {
+                    return Some(DensePolynomial::from_coefficients_vec(result));
                 }
-                let results = pool.execute_all();
-                let iter = cfg_into_iter!(results);
-                let mut result = cfg_reduce_with!(iter, |mut a, b| {
-                    cfg_iter_mut!(a).zip(b).for_each(|(a, b)| *a *= b);
-                    a
+            }
+
+            if self.fft_precomputation.is_none() {
+                self.fft_precomputation = Some(Cow::Owned(domain.precompute_fft()));
+            }
+            if self.ifft_precomputation.is_none() {
+                self.ifft_precomputation =
+                    Some(Cow::Owned(self.fft_precomputation.as_ref().unwrap().to_ifft_precomputation()));
+            }
+            let fft_pc = &self.fft_precomputation.unwrap();
+            let ifft_pc = &self.ifft_precomputation.unwrap();
+            let mut pool = ExecutionPool::with_capacity(self.polynomials.len() + self.evaluations.len());
+            for (_, p) in self.polynomials {
+                pool.add_job(move || {
+                    let mut p = p.into_owned().coeffs;
+                    p.resize(domain.size(), F::zero());
+                    domain.out_order_fft_in_place_with_pc(&mut p, fft_pc);
+                    p
+                })
+            }
+            for (_, e) in self.evaluations {
+                pool.add_job(move || {
+                    let mut e = e.into_owned().evaluations;
+                    e.resize(domain.size(), F::zero());
+                    crate::fft::domain::derange(&mut e);
+                    e
                 })
-                .unwrap();
-                domain.out_order_ifft_in_place_with_pc(&mut result, ifft_pc);
-                Some(DensePolynomial::from_coefficients_vec(result))
+            }
+            let results = pool.execute_all();
+            let iter = cfg_into_iter!(results);
+            let mut result = cfg_reduce_with!(iter, |mut a, b| {
+                cfg_iter_mut!(a).zip(b).for_each(|(a, b)| *a *= b);
+                a
+            })
+            .unwrap();
+            domain.out_order_ifft_in_place_with_pc(&mut result, ifft_pc);
+            Some(DensePolynomial::from_coefficients_vec(result))
+        }
+    }
+
+    /// Returns the domain for the product, interpolating any evaluations that are not over it.
+    fn choose_domain(&mut self) -> Option<EvaluationDomain<F>> {
+        loop {
+            let degree = self.polynomials.iter().map(|(_, p)| p.degree() + 1).sum::<usize>();
+            let domain = EvaluationDomain::new(degree)?;
+            // Growing the domain may invalidate previously matching evaluations, so repeat until none remain.
+            let (matching, mismatched): (Vec<_>, Vec<_>) =
+                std::mem::take(&mut self.evaluations).into_iter().partition(|(_, e)| e.domain() == domain);
+            self.evaluations = matching;
+            if mismatched.is_empty() {
+                return Some(domain);
+            }
+            for (label, e) in mismatched {
+                self.polynomials.push((label, Cow::Owned(e.interpolate_by_ref())));
             }
         }
     }
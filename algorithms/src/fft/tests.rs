@@ -12,12 +12,46 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::fft::{domain::*, DensePolynomial};
+use crate::fft::{domain::*, DensePolynomial, Evaluations, Polynomial, SparsePolynomial};
 use rand::Rng;
 use snarkvm_curves::bls12_377::{Fr, G1Projective};
-use snarkvm_fields::{FftField, Field, One, Zero};
+use snarkvm_fields::{FftField, FftParameters, Field, One, Zero};
 use snarkvm_utilities::rand::{TestRng, Uniform};
 
+#[test]
+fn evaluation_domain_new_checked() {
+    // Ensure a supported size matches the unchecked constructor.
+    for num_coeffs in [0, 1, 7, 1 << 10] {
+        assert_eq!(
+            EvaluationDomain::<Fr>::new_checked(num_coeffs).unwrap(),
+            EvaluationDomain::new(num_coeffs).unwrap()
+        );
+    }
+
+    // Ensure an unsupported size reports the requested size and the maximum supported size.
+    let two_adicity = <Fr as FftField>::FftParameters::TWO_ADICITY;
+    let num_coeffs = (1usize << two_adicity) + 1;
+    assert!(EvaluationDomain::<Fr>::new(num_coeffs).is_none());
+    let error = EvaluationDomain::<Fr>::new_checked(num_coeffs).unwrap_err().to_string();
+    assert!(error.contains(&num_coeffs.to_string()));
+    assert!(error.contains(&format!("2^{two_adicity}")));
+}
+
+#[test]
+fn polynomial_nonzero_coeffs() {
+    let (zero, one, two) = (Fr::zero(), Fr::one(), Fr::one().double());
+
+    // Ensure a dense polynomial yields every coefficient, but only the nonzero ones are kept.
+    let dense = Polynomial::from(DensePolynomial::from_coefficients_vec(vec![one, zero, zero, two]));
+    assert_eq!(dense.coeffs().collect::<Vec<_>>(), vec![(0, &one), (1, &zero), (2, &zero), (3, &two)]);
+    assert_eq!(dense.nonzero_coeffs().collect::<Vec<_>>(), vec![(0, &one), (3, &two)]);
+
+    // Ensure a sparse polynomial yields the same nonzero coefficients.
+    let sparse = Polynomial::from(SparsePolynomial::from_coefficients_slice(&[(3, two), (0, one)]));
+    assert_eq!(sparse.coeffs().collect::<Vec<_>>(), vec![(0, &one), (3, &two)]);
+    assert_eq!(sparse.nonzero_coeffs().collect::<Vec<_>>(), dense.nonzero_coeffs().collect::<Vec<_>>());
+}
+
 #[test]
 fn vanishing_polynomial_evaluation() {
     let rng = &mut TestRng::default();
@@ -341,3 +375,78 @@ fn evaluate_over_domain() {
         }
     }
 }
+
+#[test]
+fn evaluations_try_from_vec_and_domain() {
+    let rng = &mut TestRng::default();
+
+    for domain_size in (1..10).map(|i| 2usize.pow(i)) {
+        let domain = EvaluationDomain::<Fr>::new(domain_size).unwrap();
+        let evaluations = (0..domain_size).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        // Ensure an exact number of evaluations is accepted, and matches the unchecked constructor.
+        let checked = Evaluations::try_from_vec_and_domain(evaluations.clone(), domain).unwrap();
+        assert_eq!(checked, Evaluations::from_vec_and_domain(evaluations.clone(), domain));
+
+        // Ensure too few evaluations are rejected, rather than padded.
+        assert!(Evaluations::try_from_vec_and_domain(evaluations[..domain_size - 1].to_vec(), domain).is_err());
+
+        // Ensure too many evaluations are rejected, rather than truncated.
+        let mut extended = evaluations;
+        extended.push(Fr::rand(rng));
+        assert!(Evaluations::try_from_vec_and_domain(extended, domain).is_err());
+    }
+}
+
+#[test]
+fn evaluations_scale() {
+    let rng = &mut TestRng::default();
+
+    for domain_size in (1..10).map(|i| 2usize.pow(i)) {
+        let domain = EvaluationDomain::<Fr>::new(domain_size).unwrap();
+        let p = DensePolynomial::rand(domain_size - 1, rng);
+        let scalar = Fr::rand(rng);
+
+        // Compute the expected evaluations by interpolating, scaling, and re-evaluating.
+        let evals = p.evaluate_over_domain_by_ref(domain);
+        let expected = (&evals.interpolate_by_ref() * scalar).evaluate_over_domain(domain);
+
+        let scaled = evals.scale(scalar);
+        assert_eq!(scaled.domain(), domain);
+        assert_eq!(scaled, expected);
+
+        let mut scaled_in_place = evals;
+        scaled_in_place.scale_in_place(scalar);
+        assert_eq!(scaled_in_place.domain(), domain);
+        assert_eq!(scaled_in_place, expected);
+    }
+}
+
+#[test]
+fn polynomial_eq_canonical() {
+    let rng = &mut TestRng::default();
+
+    for degree in [0, 1, 10] {
+        let p = DensePolynomial::<Fr>::rand(degree, rng);
+
+        // Ensure trailing zero coefficients are ignored.
+        let mut padded = p.clone();
+        padded.coeffs.extend([Fr::zero(); 3]);
+        assert_ne!(p, padded);
+        assert!(p.eq_canonical(&padded));
+        assert!(padded.eq_canonical(&p));
+
+        // Ensure a different coefficient is detected.
+        let mut other = padded.clone();
+        other.coeffs[0] += Fr::one();
+        assert!(!p.eq_canonical(&other));
+
+        // Ensure a nonzero higher coefficient is detected.
+        let mut extended = padded;
+        extended.coeffs.push(Fr::one());
+        assert!(!p.eq_canonical(&extended));
+    }
+
+    // Ensure the zero polynomial equals its padded representations.
+    assert!(DensePolynomial::<Fr>::zero().eq_canonical(&DensePolynomial { coeffs: vec![Fr::zero(); 4] }));
+}
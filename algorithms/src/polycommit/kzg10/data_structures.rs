@@ -29,7 +29,7 @@ use snarkvm_utilities::{
 };
 
 use crate::srs::{UniversalProver, UniversalVerifier};
-use anyhow::Result;
+use anyhow::{bail, ensure, Result};
 use core::ops::{Add, AddAssign};
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
@@ -60,6 +60,49 @@ impl<E: PairingEngine> UniversalParams<E> {
         Ok(Self { powers, h, prepared_h, prepared_beta_h })
     }
 
+    /// Loads the universal parameters, and checks their integrity with `self_check`.
+    pub fn load_checked() -> Result<Self> {
+        let params = Self::load()?;
+        params.self_check()?;
+        Ok(params)
+    }
+
+    /// Checks the internal consistency of the parameters, to detect corruption before it causes a failed proof.
+    ///
+    /// This checks that `h` is the generator of G2, that the prepared elements match their affine counterparts,
+    /// and that the first powers of `beta * G` and `beta * gamma * G` are consecutive powers of the same `beta`
+    /// as `beta * H`. It costs six pairings, and only reads powers that are bundled with the parameters.
+    pub fn self_check(&self) -> Result<()> {
+        ensure!(self.h == E::G2Affine::prime_subgroup_generator(), "The SRS generator of G2 is incorrect");
+        let beta_h = self.beta_h();
+        ensure!(self.prepared_h == self.h.prepare(), "The SRS prepared generator of G2 is inconsistent");
+        ensure!(self.prepared_beta_h == beta_h.prepare(), "The SRS prepared beta * H is inconsistent");
+        ensure!(self.max_degree() >= 2, "The SRS supports too few powers");
+
+        // Ensures `e(next, H) = e(current, beta * H)`, i.e. that `next = beta * current`.
+        let is_next_power = |current: E::G1Affine, next: E::G1Affine| {
+            !current.is_zero() && E::pairing(next, self.h) == E::pairing(current, beta_h)
+        };
+
+        let powers_of_beta_g = self.powers_of_beta_g(0, 3)?;
+        ensure!(
+            powers_of_beta_g.windows(2).all(|pair| is_next_power(pair[0], pair[1])),
+            "The SRS powers of beta * G are inconsistent"
+        );
+
+        let powers_of_beta_times_gamma_g = self.powers_of_beta_times_gamma_g();
+        let consecutive_powers = powers_of_beta_times_gamma_g
+            .iter()
+            .find_map(|(i, current)| Some((*current, *powers_of_beta_times_gamma_g.get(&(i + 1))?)));
+        match consecutive_powers {
+            Some((current, next)) => {
+                ensure!(is_next_power(current, next), "The SRS powers of beta * gamma * G are inconsistent")
+            }
+            None => bail!("The SRS has no consecutive powers of beta * gamma * G"),
+        }
+        Ok(())
+    }
+
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
         self.powers.download_powers_for(range)
     }
@@ -519,6 +519,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_kzg10_universal_params_self_check() {
+        let pp = UniversalParams::<Bls12_377>::load_checked().unwrap();
+
+        // Ensure a corrupted generator is detected.
+        let mut corrupted = pp.clone();
+        corrupted.h = pp.beta_h();
+        assert!(corrupted.self_check().is_err());
+
+        // Ensure a prepared element that does not match its affine counterpart is detected.
+        let mut corrupted = pp.clone();
+        corrupted.prepared_beta_h = pp.prepared_h.clone();
+        assert!(corrupted.self_check().is_err());
+    }
+
     #[test]
     fn test_kzg10_universal_params_serialization() {
         let degree = 4;
@@ -681,7 +681,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 mod tests {
     #![allow(non_camel_case_types)]
 
-    use super::{CommitterKey, SonicKZG10};
+    use super::{CommitterKey, PolynomialInfo, SonicKZG10};
     use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*};
     use snarkvm_curves::bls12_377::{Bls12_377, Fq};
     use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
@@ -710,6 +710,22 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_polynomial_info_describe_mismatch() {
+        let expected = PolynomialInfo::new("h_0".into(), Some(8), None);
+
+        // Ensure matching info has no mismatches.
+        assert!(PolynomialInfo::describe_mismatch(&expected, &expected.clone()).is_empty());
+
+        // Ensure each differing field is reported.
+        let actual = PolynomialInfo::new("h_1".into(), None, Some(1));
+        assert_eq!(PolynomialInfo::describe_mismatch(&expected, &actual), vec![
+            "expected the label 'h_0', found 'h_1'".to_string(),
+            "'h_0' expected the degree bound Some(8), found None".to_string(),
+            "'h_0' expected the hiding bound None, found Some(1)".to_string(),
+        ]);
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
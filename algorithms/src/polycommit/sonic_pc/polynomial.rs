@@ -57,6 +57,28 @@ impl PolynomialInfo {
     pub fn hiding_bound(&self) -> Option<usize> {
         self.hiding_bound
     }
+
+    /// Returns a description of each label, degree bound, or hiding bound in which `actual` differs from `expected`.
+    /// The list is empty if and only if `expected` and `actual` match.
+    pub fn describe_mismatch(expected: &Self, actual: &Self) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        if expected.label != actual.label {
+            mismatches.push(format!("expected the label '{}', found '{}'", expected.label, actual.label));
+        }
+        if expected.degree_bound != actual.degree_bound {
+            mismatches.push(format!(
+                "'{}' expected the degree bound {:?}, found {:?}",
+                expected.label, expected.degree_bound, actual.degree_bound
+            ));
+        }
+        if expected.hiding_bound != actual.hiding_bound {
+            mismatches.push(format!(
+                "'{}' expected the hiding bound {:?}, found {:?}",
+                expected.label, expected.hiding_bound, actual.hiding_bound
+            ));
+        }
+        mismatches
+    }
 }
 
 /// A polynomial along with information about its degree bound (if any), and the
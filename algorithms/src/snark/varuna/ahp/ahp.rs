@@ -17,7 +17,7 @@ use crate::{
         domain::{FFTPrecomputation, IFFTPrecomputation},
         EvaluationDomain,
     },
-    polycommit::sonic_pc::{LCTerm, LabeledPolynomial, LinearCombination},
+    polycommit::sonic_pc::{LCTerm, LabeledPolynomial, LinearCombination, PolynomialLabel},
     r1cs::SynthesisError,
     snark::varuna::{
         ahp::{verifier, AHPError, CircuitId, CircuitInfo},
@@ -30,9 +30,12 @@ use crate::{
 use anyhow::{anyhow, ensure, Result};
 use snarkvm_fields::{Field, PrimeField};
 
-use core::{borrow::Borrow, marker::PhantomData};
+use core::{borrow::Borrow, cell::RefCell, marker::PhantomData};
 use itertools::Itertools;
-use std::{collections::BTreeMap, fmt::Write};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
 
 /// The algebraic holographic proof defined in [CHMMVW19](https://eprint.iacr.org/2019/1047).
 /// Currently, this AHP only supports inputs of size one
@@ -48,6 +51,27 @@ pub(crate) fn witness_label(circuit_id: CircuitId, poly: &str, i: usize) -> Stri
     label
 }
 
+/// The circuit dimensions used to compute the maximum degree of the AHP.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MaxDegreeParams {
+    /// The number of constraints.
+    pub num_constraints: usize,
+    /// The number of variables, including the "one" variable.
+    pub num_variables: usize,
+    /// The number of non-zero entries in the largest constraint matrix.
+    pub num_non_zero: usize,
+}
+
+/// The predicted shape of a proof, as returned by [`AHPForR1CS::predicted_proof_size`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ProofSizeEstimate {
+    /// The number of group elements: the oracle commitments and the evaluation proof witnesses.
+    pub num_commitments: usize,
+    /// The number of field elements: the oracle evaluations, the prover's matrix sums,
+    /// and in hiding mode the blinding evaluations of the evaluation proof.
+    pub num_field_elements: usize,
+}
+
 pub(crate) struct NonZeroDomains<F: PrimeField> {
     pub(crate) max_non_zero_domain: Option<EvaluationDomain<F>>,
     pub(crate) domain_a: EvaluationDomain<F>,
@@ -60,8 +84,25 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// These correspond to the virtual commitments as noted in the Aleo varuna protocol docs
     pub const LC_WITH_ZERO_EVAL: [&'static str; 3] = ["matrix_sumcheck", "lineval_sumcheck", "rowcheck_zerocheck"];
 
+    /// The number of queries each hiding oracle must stay zero-knowledge against, as given by [`SNARKMode::zk_bound`].
     pub fn zk_bound() -> Option<usize> {
-        SM::ZK.then_some(1)
+        SM::zk_bound()
+    }
+
+    /// The maximum degree of the rowcheck oracle `h_0`, for a constraint domain of the given size.
+    pub fn h_0_degree_bound(constraint_domain_size: usize) -> usize {
+        2 * constraint_domain_size + 2 * Self::zk_bound().unwrap_or(0) - 2
+    }
+
+    /// The degree that `h_0` is expected to have for a constraint domain of the given size.
+    /// Each instance contributes `(z_a * z_b - z_c) / v_H`, which has degree `|H| - 2` unless the rowcheck collapses.
+    pub fn h_0_expected_degree(constraint_domain_size: usize) -> usize {
+        constraint_domain_size.saturating_sub(2)
+    }
+
+    /// The maximum degree of the lineval oracle `h_1`, for a variable domain of the given size.
+    pub fn h_1_degree_bound(variable_domain_size: usize) -> usize {
+        2 * variable_domain_size + 2 * Self::zk_bound().unwrap_or(0) - 2
     }
 
     /// Check that the (formatted) public input is of the form 2^n for some integer n.
@@ -82,7 +123,6 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// The number of the variables must include the "one" variable. That is, it
     /// must be with respect to the number of formatted public inputs.
     pub fn max_degree(num_constraints: usize, num_variables: usize, num_non_zero: usize) -> Result<usize> {
-        let zk_bound = Self::zk_bound().unwrap_or(0);
         let constraint_domain_size =
             EvaluationDomain::<F>::compute_size_of_domain(num_constraints).ok_or(AHPError::PolyTooLarge)?;
         let variable_domain_size =
@@ -92,8 +132,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
         // these should correspond with the bounds set in the <round>.rs files
         [
-            2 * constraint_domain_size + 2 * zk_bound - 2,
-            2 * variable_domain_size + 2 * zk_bound - 2,
+            Self::h_0_degree_bound(constraint_domain_size),
+            Self::h_1_degree_bound(variable_domain_size),
             if SM::ZK { variable_domain_size + 3 } else { 0 }, // mask_poly
             variable_domain_size,
             constraint_domain_size,
@@ -105,6 +145,61 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         .ok_or(anyhow!("Could not find max_degree"))
     }
 
+    /// The maximum degree of polynomials produced by the indexer and prover
+    /// of this protocol, for the given named circuit dimensions.
+    pub fn max_degree_for(params: MaxDegreeParams) -> Result<usize> {
+        let MaxDegreeParams { num_constraints, num_variables, num_non_zero } = params;
+        Self::max_degree(num_constraints, num_variables, num_non_zero)
+    }
+
+    /// The number of points at which the oracles are opened, namely `alpha`, `beta` and `gamma`.
+    pub const NUM_QUERY_POINTS: usize = 3;
+
+    /// The number of query points at which a hiding oracle is opened, namely `beta`.
+    /// The oracles opened at `alpha` and `gamma` are never hiding, so their evaluation proofs are not blinded.
+    pub const NUM_HIDING_QUERY_POINTS: usize = 1;
+
+    /// Predict the size of a proof for a single instance of the given circuit.
+    /// The shape of a proof only depends on the batch layout and the [`SNARKMode`], not on the circuit dimensions.
+    pub fn predicted_proof_size(_info: &CircuitInfo) -> ProofSizeEstimate {
+        Self::predicted_batch_proof_size(&[1])
+    }
+
+    /// Predict the size of a proof for a batch, given the number of instances of each circuit.
+    pub fn predicted_batch_proof_size(batch_sizes: &[usize]) -> ProofSizeEstimate {
+        let num_circuits = batch_sizes.len();
+        let total_batch_size = batch_sizes.iter().sum();
+        let num_oracles = Self::num_first_round_oracles(total_batch_size)
+            + Self::num_second_round_oracles()
+            + Self::num_third_round_oracles()
+            + Self::num_fourth_round_oracles(num_circuits)
+            + Self::num_fifth_round_oracles();
+        // The evaluation proof holds one witness per query point, and in hiding mode a blinding evaluation for each
+        // query point with a hiding oracle.
+        let num_pc_witnesses = Self::NUM_QUERY_POINTS;
+        let num_pc_field_elements = if SM::ZK { Self::NUM_HIDING_QUERY_POINTS } else { 0 };
+        // The prover sends the evaluations of `g_1` and of `g_a`, `g_b`, `g_c` for each circuit.
+        let num_evaluations = 1 + 3 * num_circuits;
+        // The third message holds the matrix sums of each instance, the fourth message those of each circuit.
+        let num_sums = 3 * total_batch_size + 3 * num_circuits;
+        ProofSizeEstimate {
+            num_commitments: num_oracles + num_pc_witnesses,
+            num_field_elements: num_evaluations + num_sums + num_pc_field_elements,
+        }
+    }
+
+    /// Ensure that no two oracles share a label, as the oracles of all rounds
+    /// are combined into a single map when constructing the linear combinations.
+    pub fn check_unique_labels<'a>(labels: impl IntoIterator<Item = &'a str>) -> Result<(), AHPError> {
+        let mut seen = BTreeSet::new();
+        for label in labels {
+            if !seen.insert(label) {
+                return Err(AHPError::DuplicateLabel(label.to_string()));
+            }
+        }
+        Ok(())
+    }
+
     /// Get all the strict degree bounds enforced in the AHP.
     pub fn get_degree_bounds(info: &CircuitInfo) -> Result<[usize; 4]> {
         let num_variables = info.num_public_and_private_variables;
@@ -123,9 +218,9 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         info: &CircuitInfo,
         max_candidate: Option<EvaluationDomain<F>>,
     ) -> Result<NonZeroDomains<F>> {
-        let domain_a = EvaluationDomain::new(info.num_non_zero_a).ok_or(SynthesisError::PolyTooLarge)?;
-        let domain_b = EvaluationDomain::new(info.num_non_zero_b).ok_or(SynthesisError::PolyTooLarge)?;
-        let domain_c = EvaluationDomain::new(info.num_non_zero_c).ok_or(SynthesisError::PolyTooLarge)?;
+        let domain_a = EvaluationDomain::new_checked(info.num_non_zero_a)?;
+        let domain_b = EvaluationDomain::new_checked(info.num_non_zero_b)?;
+        let domain_c = EvaluationDomain::new_checked(info.num_non_zero_c)?;
         let new_candidate = [domain_a, domain_b, domain_c]
             .into_iter()
             .max_by_key(|d| d.size())
@@ -177,6 +272,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         state: &verifier::State<F, SM>,
     ) -> Result<BTreeMap<String, LinearCombination<F>>> {
         ensure!(!public_inputs.is_empty());
+        // Evaluate each polynomial at each distinct point at most once.
+        let evals = &MemoizedEvaluations::new(evals);
         let max_constraint_domain = state.max_constraint_domain;
         let max_variable_domain = state.max_variable_domain;
         let max_non_zero_domain = state.max_non_zero_domain;
@@ -387,6 +484,28 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         Ok(linear_combinations)
     }
 
+    /// Construct the linear combinations that are checked by the AHP, as in `construct_linear_combinations`,
+    /// resolving the prover's polynomials through `lookup` rather than a collection holding all of them.
+    /// This allows the oracles of each round to be loaded on demand.
+    pub fn construct_linear_combinations_with_lookup<'a, L>(
+        public_inputs: &BTreeMap<CircuitId, Vec<Vec<F>>>,
+        lookup: L,
+        prover_third_message: &prover::ThirdMessage<F>,
+        prover_fourth_message: &prover::FourthMessage<F>,
+        state: &verifier::State<F, SM>,
+    ) -> Result<BTreeMap<String, LinearCombination<F>>>
+    where
+        L: Fn(&PolynomialLabel) -> Option<&'a LabeledPolynomial<F>>,
+    {
+        Self::construct_linear_combinations(
+            public_inputs,
+            &PolynomialLookup(lookup),
+            prover_third_message,
+            prover_fourth_message,
+            state,
+        )
+    }
+
     fn construct_g_m_term(
         gamma: F,
         g_m_at_gamma: F,
@@ -453,6 +572,41 @@ pub trait EvaluationsProvider<F: PrimeField>: core::fmt::Debug {
     fn get_lc_eval(&self, lc: &LinearCombination<F>, point: F) -> Result<F>;
 }
 
+/// An `EvaluationsProvider` that memoizes the evaluations of single-polynomial linear combinations,
+/// keyed by polynomial label and point.
+#[derive(Debug)]
+struct MemoizedEvaluations<'a, F: PrimeField, E: EvaluationsProvider<F>> {
+    evals: &'a E,
+    cache: RefCell<BTreeMap<(String, F), F>>,
+}
+
+impl<'a, F: PrimeField, E: EvaluationsProvider<F>> MemoizedEvaluations<'a, F, E> {
+    fn new(evals: &'a E) -> Self {
+        Self { evals, cache: RefCell::new(BTreeMap::new()) }
+    }
+}
+
+impl<'a, F: PrimeField, E: EvaluationsProvider<F>> EvaluationsProvider<F> for MemoizedEvaluations<'a, F, E> {
+    fn get_lc_eval(&self, lc: &LinearCombination<F>, point: F) -> Result<F> {
+        // Only a linear combination consisting solely of the polynomial it is named after is cached,
+        // as its evaluation is determined by its label and the point alone.
+        let is_single_poly = matches!(
+            lc.iter().exactly_one(),
+            Ok((coeff, LCTerm::PolyLabel(label))) if coeff.is_one() && label == lc.label()
+        );
+        if !is_single_poly {
+            return self.evals.get_lc_eval(lc, point);
+        }
+        let key = (lc.label.clone(), point);
+        if let Some(eval) = self.cache.borrow().get(&key) {
+            return Ok(*eval);
+        }
+        let eval = self.evals.get_lc_eval(lc, point)?;
+        self.cache.borrow_mut().insert(key, eval);
+        Ok(eval)
+    }
+}
+
 /// The `EvaluationsProvider` used by the verifier
 impl<F: PrimeField> EvaluationsProvider<F> for crate::polycommit::sonic_pc::Evaluations<F> {
     fn get_lc_eval(&self, lc: &LinearCombination<F>, point: F) -> Result<F> {
@@ -468,31 +622,116 @@ where
     T: Borrow<LabeledPolynomial<F>> + core::fmt::Debug,
 {
     fn get_lc_eval(&self, lc: &LinearCombination<F>, point: F) -> Result<F> {
-        let mut eval = F::zero();
-        for (coeff, term) in lc.iter() {
-            let value = if let LCTerm::PolyLabel(label) = term {
-                self.iter()
-                    .find(|p| (*p).borrow().label() == label)
-                    .ok_or_else(|| AHPError::MissingEval(format!("Missing {} for {}", label, lc.label)))?
-                    .borrow()
-                    .evaluate(point)
-            } else {
-                ensure!(term.is_one());
-                F::one()
-            };
-            eval += &(*coeff * value)
-        }
-        Ok(eval)
+        evaluate_lc_with(lc, point, |label| {
+            self.iter().map(Borrow::<LabeledPolynomial<F>>::borrow).find(|p| p.label() == label)
+        })
+    }
+}
+
+/// The `EvaluationsProvider` used by the prover when its polynomials are looked up by label on demand.
+struct PolynomialLookup<L>(L);
+
+impl<L> core::fmt::Debug for PolynomialLookup<L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PolynomialLookup").finish_non_exhaustive()
+    }
+}
+
+impl<'a, F, L> EvaluationsProvider<F> for PolynomialLookup<L>
+where
+    F: PrimeField,
+    L: Fn(&PolynomialLabel) -> Option<&'a LabeledPolynomial<F>>,
+{
+    fn get_lc_eval(&self, lc: &LinearCombination<F>, point: F) -> Result<F> {
+        evaluate_lc_with(lc, point, &self.0)
     }
 }
 
+/// Evaluates `lc` at `point`, resolving each of its polynomials through `lookup`.
+fn evaluate_lc_with<'a, F: PrimeField>(
+    lc: &LinearCombination<F>,
+    point: F,
+    lookup: impl Fn(&PolynomialLabel) -> Option<&'a LabeledPolynomial<F>>,
+) -> Result<F> {
+    let mut eval = F::zero();
+    for (coeff, term) in lc.iter() {
+        let value = if let LCTerm::PolyLabel(label) = term {
+            lookup(label)
+                .ok_or_else(|| AHPError::MissingEval(format!("Missing {} for {}", label, lc.label)))?
+                .evaluate(point)
+        } else {
+            ensure!(term.is_one());
+            F::one()
+        };
+        eval += &(*coeff * value)
+    }
+    Ok(eval)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::fft::DensePolynomial;
     use snarkvm_curves::bls12_377::fr::Fr;
-    use snarkvm_fields::Zero;
-    use snarkvm_utilities::rand::TestRng;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    /// An `EvaluationsProvider` that counts how often it is queried.
+    #[derive(Debug)]
+    struct CountingEvaluations(Vec<LabeledPolynomial<Fr>>, core::cell::Cell<usize>);
+
+    impl EvaluationsProvider<Fr> for CountingEvaluations {
+        fn get_lc_eval(&self, lc: &LinearCombination<Fr>, point: Fr) -> Result<Fr> {
+            self.1.set(self.1.get() + 1);
+            self.0.get_lc_eval(lc, point)
+        }
+    }
+
+    #[test]
+    fn test_memoized_evaluations() {
+        let rng = &mut TestRng::default();
+        let polys = ["p", "q"]
+            .map(|label| LabeledPolynomial::new(label.to_string(), DensePolynomial::<Fr>::rand(8, rng), None, None));
+        let evals = CountingEvaluations(polys.to_vec(), Default::default());
+        let memoized = MemoizedEvaluations::new(&evals);
+        let (x, y) = (Fr::rand(rng), Fr::rand(rng));
+
+        // Ensure a single-polynomial linear combination is evaluated once per distinct point.
+        let p = LinearCombination::new("p", [(Fr::one(), "p")]);
+        for _ in 0..3 {
+            assert_eq!(memoized.get_lc_eval(&p, x).unwrap(), polys[0].evaluate(x));
+            assert_eq!(memoized.get_lc_eval(&p, y).unwrap(), polys[0].evaluate(y));
+        }
+        assert_eq!(evals.1.get(), 2);
+
+        // Ensure other linear combinations are always forwarded.
+        let p_plus_q = LinearCombination::new("p", [(Fr::one(), "p"), (Fr::one(), "q")]);
+        for _ in 0..3 {
+            assert_eq!(memoized.get_lc_eval(&p_plus_q, x).unwrap(), polys[0].evaluate(x) + polys[1].evaluate(x));
+        }
+        assert_eq!(evals.1.get(), 5);
+    }
+
+    #[test]
+    fn test_polynomial_lookup() {
+        let rng = &mut TestRng::default();
+        let polys = ["p", "q"]
+            .map(|label| LabeledPolynomial::new(label.to_string(), DensePolynomial::<Fr>::rand(8, rng), None, None));
+        let lookup = PolynomialLookup(|label: &PolynomialLabel| polys.iter().find(|p| p.label() == label));
+        let point = Fr::rand(rng);
+
+        // Ensure the lookup evaluates linear combinations exactly as the collected polynomials do.
+        let lc = LinearCombination::new("lc", [
+            (Fr::rand(rng), "p".into()),
+            (Fr::rand(rng), "q".into()),
+            (Fr::one(), LCTerm::One),
+        ]);
+        assert_eq!(lookup.get_lc_eval(&lc, point).unwrap(), polys.to_vec().get_lc_eval(&lc, point).unwrap());
+
+        // Ensure a polynomial the lookup cannot resolve is reported as missing.
+        let missing = LinearCombination::new("r", [(Fr::one(), "r")]);
+        assert!(lookup.get_lc_eval(&missing, point).is_err());
+    }
 
     #[test]
     fn test_summation() {
@@ -30,14 +30,23 @@ pub enum AHPError {
     #[error("The number of public inputs is incorrect.")]
     InvalidPublicInputLength,
 
+    #[error("The oracle label {} is used by more than one polynomial.", _0)]
+    DuplicateLabel(String),
+
     #[error("During verification, a required evaluation is missing: {}", _0)]
     MissingEval(String),
 
     #[error("Currently we only support square constraint matrices.")]
     NonSquareMatrix,
 
+    #[error("Constraint {2} of instance {1} in circuit {0} is not satisfied.")]
+    UnsatisfiedConstraint(crate::snark::varuna::CircuitId, usize, usize),
+
     #[error("During synthesis, our polynomials ended up being too high of degree.")]
     PolyTooLarge,
+
+    #[error("The interpolation of {} does not match its evaluations.", _0)]
+    InterpolationMismatch(String),
 }
 
 impl From<crate::r1cs::errors::SynthesisError> for AHPError {
@@ -45,3 +54,43 @@ impl From<crate::r1cs::errors::SynthesisError> for AHPError {
         AHPError::ConstraintSystemError(other)
     }
 }
+
+impl From<ProverError> for AHPError {
+    fn from(other: ProverError) -> Self {
+        AHPError::AnyhowError(other.into())
+    }
+}
+
+/// Describes the failure modes of the AHP prover rounds.
+#[derive(Debug, Error)]
+pub enum ProverError {
+    #[error("The degree of {0} ({1}) exceeds its bound ({2}).")]
+    DegreeBoundExceeded(String, usize, usize),
+
+    #[error("The degree of {0} ({1}) is far below its expected degree ({2}).")]
+    DegreeTooLow(String, usize, usize),
+
+    #[error("The batch contains no instances to prove.")]
+    EmptyBatch,
+
+    #[error("Found mismatched domains: {0}")]
+    DomainMismatch(String),
+
+    #[error("The batch combiners do not match the instances of circuit {0}.")]
+    CombinerMismatch(crate::snark::varuna::CircuitId),
+
+    #[error("{0} is not divisible by the vanishing polynomial of its domain.")]
+    NonDivisible(String),
+
+    #[error("The prover was cancelled.")]
+    Cancelled,
+
+    #[error("The prover was asked to run {got}, but expected {expected}.")]
+    OutOfOrderRound {
+        expected: crate::snark::varuna::prover::RoundStage,
+        got: crate::snark::varuna::prover::RoundStage,
+    },
+
+    #[error("{0}")]
+    Internal(#[from] anyhow::Error),
+}
@@ -21,9 +21,10 @@ mod message;
 pub(crate) use message::*;
 
 mod oracles;
-pub(crate) use oracles::*;
+pub use oracles::*;
 
 mod round_functions;
 
 mod state;
+pub use state::{DegreeHistogram, MaxDomains, ProverConfig, RoundStage, RoundTiming, State, WitnessField};
 use state::*;
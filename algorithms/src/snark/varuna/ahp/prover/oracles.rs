@@ -16,11 +16,24 @@ use std::collections::BTreeMap;
 
 use snarkvm_fields::PrimeField;
 
+#[cfg(any(test, feature = "test"))]
+use crate::fft::DensePolynomial;
 use crate::{
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::CircuitId,
 };
 
+/// Returns a description of each way in which the info of `polynomial` differs from its expected info in `info`.
+fn expected_info_mismatches<F: PrimeField>(
+    polynomial: &LabeledPolynomial<F>,
+    info: &BTreeMap<PolynomialLabel, PolynomialInfo>,
+) -> Vec<String> {
+    match info.get(polynomial.label()) {
+        Some(expected) => PolynomialInfo::describe_mismatch(expected, polynomial.info()),
+        None => vec![format!("'{}' has no expected info", polynomial.label())],
+    }
+}
+
 /// The first set of prover oracles.
 #[derive(Debug, Clone)]
 pub struct FirstOracles<F: PrimeField> {
@@ -36,13 +49,18 @@ impl<F: PrimeField> FirstOracles<F> {
     }
 
     /// Iterate over the polynomials output by the prover in the first round.
-    pub fn into_iter(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
+    pub fn into_polynomials(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
         self.batches.into_values().flat_map(|b| b.into_iter()).map(|b| b.0).chain(self.mask_poly)
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
         self.batches.values().all(|b| b.iter().all(|b| b.matches_info(info)))
-            && self.mask_poly.as_ref().map_or(true, |p| Some(p.info()) == info.get(p.label()))
+            && self.mask_poly.as_ref().map_or(true, |p| expected_info_mismatches(p, info).is_empty())
+    }
+
+    /// Returns a description of each way in which the oracles differ from their expected info in `info`.
+    pub fn info_mismatches(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> Vec<String> {
+        self.iter().flat_map(|p| expected_info_mismatches(p, info)).collect()
     }
 }
 
@@ -57,7 +75,7 @@ impl<F: PrimeField> WitnessPoly<F> {
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
-        Some(self.0.info()) == info.get(self.0.label())
+        expected_info_mismatches(&self.0, info).is_empty()
     }
 }
 
@@ -69,18 +87,30 @@ pub struct SecondOracles<F: PrimeField> {
 }
 
 impl<F: PrimeField> SecondOracles<F> {
+    /// Constructs the second round oracles from the given `h_0`, bypassing the prover's second round.
+    /// This allows verifier tests to use controlled oracles, such as a deliberately malformed `h_0`.
+    #[cfg(any(test, feature = "test"))]
+    pub fn from_h_0(h_0: DensePolynomial<F>) -> Self {
+        Self { h_0: LabeledPolynomial::new("h_0", h_0, None, None) }
+    }
+
     /// Iterate over the polynomials output by the prover in the second round.
     pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
         [&self.h_0].into_iter()
     }
 
     /// Iterate over the polynomials output by the prover in the second round.
-    pub fn into_iter(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
+    pub fn into_polynomials(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
         [self.h_0].into_iter()
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
-        Some(self.h_0.info()) == info.get(self.h_0.label())
+        self.info_mismatches(info).is_empty()
+    }
+
+    /// Returns a description of each way in which the oracles differ from their expected info in `info`.
+    pub fn info_mismatches(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> Vec<String> {
+        self.iter().flat_map(|p| expected_info_mismatches(p, info)).collect()
     }
 }
 
@@ -100,12 +130,17 @@ impl<F: PrimeField> ThirdOracles<F> {
     }
 
     /// Iterate over the polynomials output by the prover in the third round.
-    pub fn into_iter(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
+    pub fn into_polynomials(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
         [self.g_1, self.h_1].into_iter()
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
-        Some(self.h_1.info()) == info.get(self.h_1.label()) && Some(self.g_1.info()) == info.get(self.g_1.label())
+        self.info_mismatches(info).is_empty()
+    }
+
+    /// Returns a description of each way in which the oracles differ from their expected info in `info`.
+    pub fn info_mismatches(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> Vec<String> {
+        self.iter().flat_map(|p| expected_info_mismatches(p, info)).collect()
     }
 }
 
@@ -127,9 +162,7 @@ pub(in crate::snark::varuna) struct MatrixGs<F: PrimeField> {
 
 impl<F: PrimeField> MatrixGs<F> {
     pub fn matches_matrix_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
-        Some(self.g_a.info()) == info.get(self.g_a.label())
-            && Some(self.g_b.info()) == info.get(self.g_b.label())
-            && Some(self.g_c.info()) == info.get(self.g_c.label())
+        [&self.g_a, &self.g_b, &self.g_c].into_iter().all(|p| expected_info_mismatches(p, info).is_empty())
     }
 }
 
@@ -140,13 +173,18 @@ impl<F: PrimeField> FourthOracles<F> {
     }
 
     /// Iterate over the polynomials output by the prover in the fourth round.
-    pub fn into_iter(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
+    pub fn into_polynomials(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
         self.gs.into_values().flat_map(|gs| [gs.g_a, gs.g_b, gs.g_c].into_iter())
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
         self.gs.values().all(|b| b.matches_matrix_info(info))
     }
+
+    /// Returns a description of each way in which the oracles differ from their expected info in `info`.
+    pub fn info_mismatches(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> Vec<String> {
+        self.iter().flat_map(|p| expected_info_mismatches(p, info)).collect()
+    }
 }
 
 #[derive(Debug)]
@@ -162,11 +200,16 @@ impl<F: PrimeField> FifthOracles<F> {
     }
 
     /// Iterate over the polynomials output by the prover in the previous round.
-    pub fn into_iter(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
+    pub fn into_polynomials(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
         [self.h_2].into_iter()
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
-        Some(self.h_2.info()) == info.get(self.h_2.label())
+        self.info_mismatches(info).is_empty()
+    }
+
+    /// Returns a description of each way in which the oracles differ from their expected info in `info`.
+    pub fn info_mismatches(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> Vec<String> {
+        self.iter().flat_map(|p| expected_info_mismatches(p, info)).collect()
     }
 }
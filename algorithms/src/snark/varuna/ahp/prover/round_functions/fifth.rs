@@ -38,13 +38,20 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         1
     }
 
-    /// Output the fifth round message and the next state.
+    /// Output the fifth round message, along with the histogram of the degrees of all constructed polynomials,
+    /// if enabled.
     pub fn prover_fifth_round<R: RngCore>(
         verifier_message: verifier::FourthMessage<F>,
-        state: prover::State<'_, F, SM>,
+        mut state: prover::State<'_, F, SM>,
         _r: &mut R,
-    ) -> Result<prover::FifthOracles<F>, AHPError> {
+    ) -> Result<(prover::FifthOracles<F>, Option<prover::DegreeHistogram>), AHPError>
+    where
+        F: prover::WitnessField,
+    {
+        state.enter_round(prover::RoundStage::Fifth)?;
         let round_time = start_timer!(|| "AHP::Prover::FifthRound");
+        // The state is consumed below, so the degree histogram is taken out and returned by this last round.
+        let degree_histogram = state.degree_histogram.take();
 
         let lhs_sum: DensePolynomial<F> = cfg_reduce!(
             cfg_par_bridge!(verifier_message.into_iter().zip_eq(state.lhs_polys_into_iter())).map(
@@ -63,10 +70,14 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         );
         let h_2 = LabeledPolynomial::new("h_2", lhs_sum, None, None);
         let oracles = prover::FifthOracles { h_2 };
-        assert!(oracles.matches_info(&Self::fifth_round_polynomial_info()));
+        let mismatches = oracles.info_mismatches(&Self::fifth_round_polynomial_info());
+        assert!(mismatches.is_empty(), "The fifth round oracles do not match their polynomial info: {mismatches:?}");
+        if let Some(degree_histogram) = &degree_histogram {
+            degree_histogram.record_all(oracles.iter());
+        }
 
         end_timer!(round_time);
-        Ok(oracles)
+        Ok((oracles, degree_histogram))
     }
 
     /// Output the degree bounds of oracles in the last round.
@@ -29,7 +29,7 @@ use snarkvm_utilities::cfg_into_iter;
 
 use itertools::Itertools;
 use rand_core::RngCore;
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -60,14 +60,18 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     pub fn prover_first_round<'a, R: RngCore>(
         mut state: prover::State<'a, F, SM>,
         rng: &mut R,
-    ) -> Result<prover::State<'a, F, SM>, AHPError> {
+    ) -> Result<prover::State<'a, F, SM>, AHPError>
+    where
+        F: prover::WitnessField,
+    {
+        state.enter_round(prover::RoundStage::First)?;
         let round_time = start_timer!(|| "AHP::Prover::FirstRound");
         let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(state.total_instances);
         for (circuit, circuit_state) in state.circuit_specific_states.iter_mut() {
             let batch_size = circuit_state.batch_size;
 
-            let private_variables = core::mem::take(&mut circuit_state.private_variables);
-            let x_polys = circuit_state.x_polys.clone();
+            let private_variables = circuit_state.take_private_variables();
+            let x_polys = circuit_state.x_polys().to_vec();
             assert_eq!(private_variables.len(), batch_size);
             assert_eq!(x_polys.len(), batch_size);
 
@@ -90,10 +94,13 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         }
         let mask_poly = SM::ZK.then(|| Self::calculate_mask_poly(state.max_variable_domain, rng));
         let oracles = prover::FirstOracles { batches: circuit_specific_batches, mask_poly };
-        assert!(oracles.matches_info(&Self::first_round_polynomial_info(
-            state.circuit_specific_states.iter().map(|(c, s)| (&c.id, &s.batch_size))
-        )));
-        state.first_round_oracles = Some(oracles);
+        let mismatches = oracles.info_mismatches(&Self::first_round_polynomial_info(
+            state.circuit_specific_states.iter().map(|(c, s)| (&c.id, &s.batch_size)),
+        ));
+        assert!(mismatches.is_empty(), "The first round oracles do not match their polynomial info: {mismatches:?}");
+        state.record_degrees(oracles.iter());
+        state.absorb_round_outputs(&[], oracles.iter())?;
+        state.first_round_oracles = Some(Arc::new(oracles));
         end_timer!(round_time);
         Ok(state)
     }
@@ -80,7 +80,11 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         third_message: &verifier::ThirdMessage<F>,
         mut state: prover::State<'a, F, SM>,
         _r: &mut R,
-    ) -> Result<(prover::FourthMessage<F>, prover::FourthOracles<F>, prover::State<'a, F, SM>), AHPError> {
+    ) -> Result<(prover::FourthMessage<F>, prover::FourthOracles<F>, prover::State<'a, F, SM>), AHPError>
+    where
+        F: prover::WitnessField,
+    {
+        state.enter_round(prover::RoundStage::Fourth)?;
         let round_time = start_timer!(|| "AHP::Prover::FourthRound");
 
         let verifier::SecondMessage { alpha, .. } = second_message;
@@ -141,9 +145,12 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let msg = prover::FourthMessage { sums };
         let oracles = prover::FourthOracles { gs };
 
-        assert!(oracles.matches_info(&Self::fourth_round_polynomial_info(
-            state.circuit_specific_states.keys().map(|c| (c.id, &c.index_info))
-        )));
+        let mismatches = oracles.info_mismatches(&Self::fourth_round_polynomial_info(
+            state.circuit_specific_states.keys().map(|c| (c.id, &c.index_info)),
+        ));
+        assert!(mismatches.is_empty(), "The fourth round oracles do not match their polynomial info: {mismatches:?}");
+        state.record_degrees(oracles.iter());
+        state.absorb_round_outputs(&[*beta], oracles.iter())?;
 
         end_timer!(round_time);
 
@@ -43,10 +43,15 @@ mod third;
 
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// Initialize the AHP prover.
+    /// With the `check_assignments` feature, this aborts if any circuit's assignments are unsatisfied,
+    /// before any round of the prover is run.
     pub fn init_prover<'a, C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
         circuits_to_constraints: &BTreeMap<&'a Circuit<F, SM>, &[C]>,
         rng: &mut R,
-    ) -> Result<prover::State<'a, F, SM>, AHPError> {
+    ) -> Result<prover::State<'a, F, SM>, AHPError>
+    where
+        F: prover::WitnessField,
+    {
         let init_time = start_timer!(|| "AHP::Prover::Init");
 
         let mut randomizing_assignments = Vec::with_capacity(circuits_to_constraints.len());
@@ -161,10 +166,38 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             .collect::<Result<BTreeMap<&'a Circuit<F, SM>, Vec<prover::Assignments<F>>>, AHPError>>()?;
 
         let state = prover::State::initialize(indices_and_assignments)?;
+        if cfg!(feature = "check_assignments") {
+            Self::check_assignments(&state)?;
+        }
         end_timer!(init_time);
 
         Ok(state)
     }
+
+    /// Check that the assignments in the prover state satisfy the constraints of each circuit,
+    /// i.e. that `(A z) * (B z) = (C z)` holds row-wise for every instance in the batch.
+    /// Returns the first unsatisfied constraint found, if any.
+    /// This must be called before the second round, which consumes the assignments.
+    pub fn check_assignments(state: &prover::State<F, SM>) -> Result<(), AHPError>
+    where
+        F: prover::WitnessField,
+    {
+        let check_time = start_timer!(|| "AHP::Prover::CheckAssignments");
+        for (circuit, circuit_specific_state) in state.circuit_specific_states.iter() {
+            let (Some(z_as), Some(z_bs), Some(z_cs)) =
+                (&circuit_specific_state.z_a, &circuit_specific_state.z_b, &circuit_specific_state.z_c)
+            else {
+                return Err(anyhow::anyhow!("The assignments for circuit {} were already consumed", circuit.id).into());
+            };
+            for (i, (z_a, z_b, z_c)) in itertools::izip!(z_as, z_bs, z_cs).enumerate() {
+                if let Some(index) = itertools::izip!(z_a, z_b, z_c).position(|(a, b, c)| *a * b != *c) {
+                    return Err(AHPError::UnsatisfiedConstraint(circuit.id, i, index));
+                }
+            }
+        }
+        end_timer!(check_time);
+        Ok(())
+    }
 }
 
 fn inner_product<F: PrimeField>(
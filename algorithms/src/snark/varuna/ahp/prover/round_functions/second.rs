@@ -15,11 +15,17 @@
 use std::collections::BTreeMap;
 
 use crate::{
-    fft::{polynomial::PolyMultiplier, DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    fft::{
+        domain::IFFTPrecomputation,
+        polynomial::PolyMultiplier,
+        DensePolynomial,
+        EvaluationDomain,
+        Evaluations as EvaluationsOnDomain,
+    },
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::{
-        ahp::{verifier, AHPForR1CS},
-        prover,
+        ahp::{verifier, AHPError, AHPForR1CS, ProverError},
+        prover::{self, clear_witness, RoundPhase, RoundTimer},
         selectors::apply_randomized_selector,
         witness_label,
         Circuit,
@@ -27,7 +33,8 @@ use crate::{
         SNARKMode,
     },
 };
-use anyhow::Result;
+use anyhow::{anyhow, ensure, Result};
+use itertools::Itertools;
 use rand_core::RngCore;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{cfg_into_iter, cfg_iter_mut, cfg_reduce, ExecutionPool};
@@ -35,6 +42,31 @@ use snarkvm_utilities::{cfg_into_iter, cfg_iter_mut, cfg_reduce, ExecutionPool};
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// Asserts at compile time that `T` can be moved to another thread.
+const fn assert_send<T: Send>() {}
+
+/// Asserts at compile time that `T` can be shared between threads.
+const fn assert_sync<T: Sync>() {}
+
+/// The optional diagnostics of the second round, which are shared by all of its jobs.
+#[derive(Copy, Clone, Default)]
+pub(in crate::snark::varuna) struct RoundDiagnostics<'a> {
+    /// The histogram of the degrees of the constructed polynomials, if enabled.
+    pub(in crate::snark::varuna) degree_histogram: Option<&'a prover::DegreeHistogram>,
+    /// Whether to verify each `z_m` interpolation against its evaluations.
+    pub(in crate::snark::varuna) verify_interpolations: bool,
+    /// The timer of the phases of the round, if enabled.
+    pub(in crate::snark::varuna) timer: Option<&'a RoundTimer>,
+}
+
+/// The `z_a`, `z_b`, and `z_c` of a single instance.
+enum InstanceWitness<F: PrimeField> {
+    /// The evaluations over the constraint domain.
+    Evaluations(Vec<F>, Vec<F>, Vec<F>),
+    /// The polynomials interpolated over the constraint domain.
+    Polynomials(DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>),
+}
+
 impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// Output the number of oracles sent by the prover in the second round.
     pub const fn num_second_round_oracles() -> usize {
@@ -42,6 +74,9 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     }
 
     /// Output the degree bounds of oracles in the second round.
+    /// `h_0` is committed without a hiding bound in every mode. In a hiding mode, it is computed from the witnesses
+    /// that were masked in the first round, so it carries their randomness already. It is only opened at `alpha`,
+    /// through `rowcheck_zerocheck`, whose value the verifier can compute from the third round sums anyway.
     pub fn second_round_polynomial_info() -> BTreeMap<PolynomialLabel, PolynomialInfo> {
         [PolynomialInfo::new("h_0".into(), None, None)].into_iter().map(|info| (info.label().into(), info)).collect()
     }
@@ -51,120 +86,558 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         verifier_message: &verifier::FirstMessage<F>,
         mut state: prover::State<'a, F, SM>,
         _r: &mut R,
-    ) -> Result<(prover::SecondOracles<F>, prover::State<'a, F, SM>)> {
+    ) -> Result<(prover::SecondOracles<F>, prover::State<'a, F, SM>), ProverError>
+    where
+        F: prover::WitnessField,
+    {
+        state.enter_round(prover::RoundStage::Second)?;
         let round_time = start_timer!(|| "AHP::Prover::SecondRound");
 
-        let zk_bound = Self::zk_bound();
+        let zk_bound = SM::zk_bound();
 
         let max_constraint_domain = state.max_constraint_domain;
 
         let verifier::FirstMessage { batch_combiners, .. } = verifier_message;
 
+        // A mode must add hiding terms to the rowcheck oracle if and only if it is hiding.
+        debug_assert_eq!(SM::ZK, zk_bound.is_some(), "SNARKMode produced a zk_bound of {zk_bound:?}");
+
         let h_0 = Self::calculate_rowcheck_witness(&mut state, batch_combiners)?;
 
-        assert!(h_0.degree() <= 2 * max_constraint_domain.size() + 2 * zk_bound.unwrap_or(0) - 2);
+        let h_0_degree_bound = Self::h_0_degree_bound(max_constraint_domain.size());
+        if h_0.degree() > h_0_degree_bound {
+            return Err(ProverError::DegreeBoundExceeded("h_0".to_string(), h_0.degree(), h_0_degree_bound));
+        }
+        debug_assert!(
+            SM::ZK || h_0.degree() <= 2 * max_constraint_domain.size() - 2,
+            "h_0 exceeds the non-hiding degree bound"
+        );
+        if let Err(error) = Self::check_h_0_degree(&h_0, max_constraint_domain) {
+            match cfg!(debug_assertions) {
+                true => return Err(error),
+                false => state.warnings.push(error),
+            }
+        }
 
         let oracles = prover::SecondOracles { h_0: LabeledPolynomial::new("h_0", h_0, None, None) };
-        assert!(oracles.matches_info(&Self::second_round_polynomial_info()));
+        let mismatches = oracles.info_mismatches(&Self::second_round_polynomial_info());
+        if !mismatches.is_empty() {
+            return Err(anyhow!("The second round oracles do not match their polynomial info: {mismatches:?}").into());
+        }
+        debug_assert!(SM::ZK || !oracles.h_0.is_hiding(), "h_0 is hiding in a non-hiding SNARKMode");
+        state.record_degrees(oracles.iter());
+        let combiners = batch_combiners
+            .values()
+            .flat_map(|combiners| [combiners.circuit_combiner].into_iter().chain(combiners.instance_combiners.clone()))
+            .collect_vec();
+        state.absorb_round_outputs(&combiners, oracles.iter())?;
 
         end_timer!(round_time);
 
         Ok((oracles, state))
     }
 
+    /// Output the second round message and the next state, along with the time spent in each phase of the round.
+    /// The phases are timed within each instance, so with parallelism their sum may exceed the elapsed time.
+    pub fn prover_second_round_timed<'a, R: RngCore>(
+        verifier_message: &verifier::FirstMessage<F>,
+        mut state: prover::State<'a, F, SM>,
+        r: &mut R,
+    ) -> Result<(prover::SecondOracles<F>, prover::State<'a, F, SM>, prover::RoundTiming), ProverError>
+    where
+        F: prover::WitnessField,
+    {
+        state.round_timer = Some(RoundTimer::default());
+        let (oracles, mut state) = Self::prover_second_round(verifier_message, state, r)?;
+        let timing = state.round_timer.take().map(RoundTimer::into_timing).unwrap_or_default();
+        Ok((oracles, state, timing))
+    }
+
     fn calculate_rowcheck_witness(
         state: &mut prover::State<F, SM>,
         batch_combiners: &BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
-    ) -> Result<DensePolynomial<F>> {
-        let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len());
+    ) -> Result<DensePolynomial<F>, ProverError>
+    where
+        F: prover::WitnessField,
+    {
+        // The rowcheck multiplies with both precomputations, which is only sound if they share a domain.
+        for circuit in state.circuit_specific_states.keys() {
+            debug_assert_eq!(
+                circuit.fft_precomputation.domain().size(),
+                circuit.ifft_precomputation.domain().size(),
+                "The FFT and IFFT precomputations of circuit {} are for different domain sizes",
+                circuit.id
+            );
+        }
+        // An empty batch has no meaningful constraint domain.
+        // A single instance does not need to be scheduled or folded.
+        match state.circuit_specific_states.values().map(|state| state.batch_size).sum::<usize>() {
+            0 => Err(ProverError::EmptyBatch),
+            1 => Self::calculate_single_rowcheck_witness(state, batch_combiners),
+            _ => Self::calculate_batched_rowcheck_witness(state, batch_combiners),
+        }
+    }
+
+    /// Computes `h_0` for a batch consisting of exactly one instance of one circuit.
+    pub(in crate::snark::varuna) fn calculate_single_rowcheck_witness(
+        state: &mut prover::State<F, SM>,
+        batch_combiners: &BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
+    ) -> Result<DensePolynomial<F>, ProverError>
+    where
+        F: prover::WitnessField,
+    {
         let max_constraint_domain = state.max_constraint_domain;
+        let collect_artifacts = state.witness_artifacts.is_some();
+        let diagnostics = RoundDiagnostics {
+            degree_histogram: state.degree_histogram.as_ref(),
+            verify_interpolations: state.config.verify_interpolations,
+            timer: state.round_timer.as_ref(),
+        };
+
+        let Some((circuit, circuit_specific_state)) = state.circuit_specific_states.iter_mut().next() else {
+            return Err(ProverError::EmptyBatch);
+        };
+        if circuit_specific_state.batch_size != 1 {
+            return Err(anyhow!("Expected a single instance of circuit {}", circuit.id).into());
+        }
+        let (z_a, z_b, z_c) = circuit_specific_state.take_z_m();
+        let z_a = z_a.unwrap().pop().unwrap();
+        let z_b = z_b.unwrap().pop().unwrap();
+        let z_c = z_c.unwrap().pop().unwrap();
+
+        let combiners = Self::circuit_batch_combiners(batch_combiners, circuit, 1)?;
+        let circuit_combiner = combiners.circuit_combiner;
+        let instance_combiner = combiners.instance_combiners[0];
+        let constraint_domain = circuit_specific_state.constraint_domain;
+
+        let (h_0, artifacts) = Self::calculate_instance_rowcheck_witness(
+            0,
+            circuit,
+            InstanceWitness::Evaluations(z_a, z_b, z_c),
+            (circuit_combiner, instance_combiner),
+            constraint_domain,
+            max_constraint_domain,
+            collect_artifacts,
+            diagnostics,
+        )?;
+        if let Some(witness_artifacts) = state.witness_artifacts.as_mut() {
+            witness_artifacts.polynomials.extend(artifacts);
+        }
+        Ok(h_0)
+    }
+
+    /// Computes `h_0` for a batch of any number of instances, by computing each instance in parallel and summing.
+    pub(in crate::snark::varuna) fn calculate_batched_rowcheck_witness(
+        state: &mut prover::State<F, SM>,
+        batch_combiners: &BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
+    ) -> Result<DensePolynomial<F>, ProverError>
+    where
+        F: prover::WitnessField,
+    {
+        Self::calculate_batched_rowcheck_witness_in_order(state, batch_combiners, |_| ())
+    }
+
+    /// Computes `h_0` for a batch of any number of instances, submitting the instance jobs in the order
+    /// produced by `reorder`, which permutes the job indices in place.
+    ///
+    /// As field addition is associative and commutative, `h_0` must not depend on this order;
+    /// the order is only configurable so that tests can check this.
+    pub(in crate::snark::varuna) fn calculate_batched_rowcheck_witness_in_order(
+        state: &mut prover::State<F, SM>,
+        batch_combiners: &BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
+        reorder: impl FnOnce(&mut [usize]),
+    ) -> Result<DensePolynomial<F>, ProverError>
+    where
+        F: prover::WitnessField,
+    {
+        // The jobs run on the thread pool, so what they capture must be `Send`, or `Sync` if captured by reference.
+        // Asserting this here reports an offending type directly, instead of as an unmet bound of a job closure.
+        assert_send::<InstanceWitness<F>>();
+        assert_send::<EvaluationDomain<F>>();
+        assert_sync::<Circuit<F, SM>>();
+        assert_sync::<prover::DegreeHistogram>();
+        // The results are collected across threads, so they must be both `Send` and `Sync`.
+        assert_send::<(DensePolynomial<F>, ProverError)>();
+        assert_sync::<(DensePolynomial<F>, ProverError)>();
+
+        let mut jobs = Vec::with_capacity(state.circuit_specific_states.len());
+        let max_constraint_domain = state.max_constraint_domain;
+        let collect_artifacts = state.witness_artifacts.is_some();
+        let diagnostics = RoundDiagnostics {
+            degree_histogram: state.degree_histogram.as_ref(),
+            verify_interpolations: state.config.verify_interpolations,
+            timer: state.round_timer.as_ref(),
+        };
 
         for (circuit, circuit_specific_state) in state.circuit_specific_states.iter_mut() {
-            let z_a = circuit_specific_state.z_a.take().unwrap();
-            let z_b = circuit_specific_state.z_b.take().unwrap();
-            let z_c = circuit_specific_state.z_c.take().unwrap();
+            let (z_a, z_b, z_c) = circuit_specific_state.take_z_m();
+            let (z_a, z_b, z_c) = (z_a.unwrap(), z_b.unwrap(), z_c.unwrap());
 
-            let circuit_combiner = batch_combiners[&circuit.id].circuit_combiner;
-            let instance_combiners = batch_combiners[&circuit.id].instance_combiners.clone();
+            let combiners = Self::circuit_batch_combiners(batch_combiners, circuit, circuit_specific_state.batch_size)?;
+            let circuit_combiner = combiners.circuit_combiner;
+            let instance_combiners = combiners.instance_combiners.clone();
             let constraint_domain = circuit_specific_state.constraint_domain;
-            let fft_precomputation = &circuit.fft_precomputation;
-            let ifft_precomputation = &circuit.ifft_precomputation;
-
-            let _circuit_id = &circuit.id; // seems like a compiler bug marks this as unused
-
-            for (j, (instance_combiner, z_a, z_b, z_c)) in
-                itertools::izip!(instance_combiners, z_a, z_b, z_c).enumerate()
-            {
-                job_pool.add_job(move || {
-                    let mut instance_lhs = DensePolynomial::zero();
-                    let za_label = witness_label(circuit.id, "z_a", j);
-                    let zb_label = witness_label(circuit.id, "z_b", j);
-                    let zc_label = witness_label(circuit.id, "z_c", j);
-                    let z_a = Self::calculate_z_m(za_label, z_a, constraint_domain, circuit);
-                    let z_b = Self::calculate_z_m(zb_label, z_b, constraint_domain, circuit);
-                    let z_c = Self::calculate_z_m(zc_label, z_c, constraint_domain, circuit);
-                    let mut multiplier_2 = PolyMultiplier::new();
-                    multiplier_2.add_precomputation(fft_precomputation, ifft_precomputation);
-                    multiplier_2.add_polynomial(z_a, "z_a");
-                    multiplier_2.add_polynomial(z_b, "z_b");
-                    let mut rowcheck = multiplier_2.multiply().unwrap();
-                    cfg_iter_mut!(rowcheck.coeffs).zip(&z_c.coeffs).for_each(|(ab, c)| *ab -= c);
-
-                    instance_lhs += &(&rowcheck * instance_combiner);
-
-                    let (h_0_i, remainder) = apply_randomized_selector(
-                        &mut instance_lhs,
-                        circuit_combiner,
-                        &max_constraint_domain,
-                        &constraint_domain,
-                        false,
-                    )?;
-                    assert!(remainder.is_none());
-                    Ok::<_, anyhow::Error>(h_0_i)
-                });
+
+            // The instances of a circuit share their constraint domain, so they are interpolated together.
+            let batch_size = circuit_specific_state.batch_size;
+            let witnesses = match batch_size {
+                1 => {
+                    itertools::izip!(z_a, z_b, z_c).map(|(a, b, c)| InstanceWitness::Evaluations(a, b, c)).collect_vec()
+                }
+                _ => {
+                    let labels = ["z_a", "z_b", "z_c"]
+                        .into_iter()
+                        .flat_map(|m| (0..batch_size).map(move |j| witness_label(circuit.id, m, j)))
+                        .collect();
+                    let evaluations = z_a.into_iter().chain(z_b).chain(z_c).collect();
+                    let z_ms = Self::calculate_z_m_batch(labels, evaluations, constraint_domain, circuit, diagnostics)?;
+                    let mut z_ms = z_ms.into_iter();
+                    let z_a = z_ms.by_ref().take(batch_size).collect_vec();
+                    let z_b = z_ms.by_ref().take(batch_size).collect_vec();
+                    let z_c = z_ms.collect_vec();
+                    itertools::izip!(z_a, z_b, z_c).map(|(a, b, c)| InstanceWitness::Polynomials(a, b, c)).collect_vec()
+                }
+            };
+
+            // The jobs copy the `&Circuit` out of the map key, rather than borrowing the loop variable.
+            let circuit = *circuit;
+            for (j, (instance_combiner, witness)) in instance_combiners.into_iter().zip(witnesses).enumerate() {
+                jobs.push(Some(move || {
+                    Self::calculate_instance_rowcheck_witness(
+                        j,
+                        circuit,
+                        witness,
+                        (circuit_combiner, instance_combiner),
+                        constraint_domain,
+                        max_constraint_domain,
+                        collect_artifacts,
+                        diagnostics,
+                    )
+                }));
+            }
+        }
+
+        let mut order = (0..jobs.len()).collect::<Vec<_>>();
+        reorder(&mut order);
+        if order.len() != jobs.len() {
+            return Err(anyhow!("The job order is not a permutation of the jobs").into());
+        }
+        let mut job_pool = ExecutionPool::with_capacity(jobs.len());
+        for i in order {
+            let job = jobs.get_mut(i).and_then(Option::take);
+            job_pool.add_job(job.ok_or_else(|| anyhow!("The job order is not a permutation of the jobs"))?);
+        }
+
+        let results = job_pool.execute_all();
+        let mut h_0s = Vec::with_capacity(results.len());
+        for result in results {
+            let (h_0_i, artifacts) = result?;
+            if let Some(witness_artifacts) = state.witness_artifacts.as_mut() {
+                witness_artifacts.polynomials.extend(artifacts);
             }
+            h_0s.push(h_0_i);
         }
 
         let h_sum_time = start_timer!(|| "AHP::Prover::SecondRound h_sum");
-        let h_sum: DensePolynomial<F> =
-            cfg_reduce!(cfg_into_iter!(job_pool.execute_all()), || Ok(DensePolynomial::zero()), |a, b| {
-                a.and_then(|a| {
-                    b.map(|mut b| {
-                        b += &a;
-                        b
-                    })
-                })
-            })?;
+        let h_sum: DensePolynomial<F> = RoundTimer::time(diagnostics.timer, RoundPhase::HSum, || {
+            cfg_reduce!(cfg_into_iter!(h_0s), DensePolynomial::zero, |a, mut b| {
+                // Note: This moves, rather than adds, `a` into `b` when `b` is zero.
+                b += a;
+                b
+            })
+        });
         end_timer!(h_sum_time);
 
         Ok(h_sum)
     }
 
-    fn calculate_z_m(
+    /// Returns the batch combiners for `circuit`, ensuring there is one instance combiner per instance.
+    fn circuit_batch_combiners<'b>(
+        batch_combiners: &'b BTreeMap<CircuitId, verifier::BatchCombiners<F>>,
+        circuit: &Circuit<F, SM>,
+        batch_size: usize,
+    ) -> Result<&'b verifier::BatchCombiners<F>, ProverError> {
+        match batch_combiners.get(&circuit.id) {
+            Some(combiners) if combiners.instance_combiners.len() == batch_size => Ok(combiners),
+            _ => Err(ProverError::CombinerMismatch(circuit.id)),
+        }
+    }
+
+    /// Computes the contribution of the `j`-th instance of `circuit` to `h_0`,
+    /// given its `(z_a, z_b, z_c)` and its `(circuit_combiner, instance_combiner)`.
+    #[allow(clippy::too_many_arguments)]
+    fn calculate_instance_rowcheck_witness(
+        j: usize,
+        circuit: &Circuit<F, SM>,
+        witness: InstanceWitness<F>,
+        (circuit_combiner, instance_combiner): (F, F),
+        constraint_domain: EvaluationDomain<F>,
+        max_constraint_domain: EvaluationDomain<F>,
+        collect_artifacts: bool,
+        diagnostics: RoundDiagnostics<'_>,
+    ) -> Result<(DensePolynomial<F>, Vec<(String, DensePolynomial<F>)>), ProverError>
+    where
+        F: prover::WitnessField,
+    {
+        if constraint_domain.size() > max_constraint_domain.size() {
+            return Err(ProverError::DomainMismatch(format!(
+                "the constraint domain of circuit {} ({}) exceeds the maximum constraint domain ({})",
+                circuit.id,
+                constraint_domain.size(),
+                max_constraint_domain.size()
+            )));
+        }
+        // Ensure a panic within a single instance is reported as an error, instead of aborting the batch.
+        let job = std::panic::AssertUnwindSafe(|| -> Result<_, ProverError> {
+            let mut instance_lhs = DensePolynomial::zero();
+            let (rowcheck, artifacts) = match witness {
+                InstanceWitness::Evaluations(z_a, z_b, z_c) => Self::calculate_rowcheck(
+                    j,
+                    (z_a, z_b, z_c),
+                    constraint_domain,
+                    circuit,
+                    collect_artifacts,
+                    diagnostics,
+                )?,
+                InstanceWitness::Polynomials(z_a, z_b, z_c) => {
+                    Self::calculate_rowcheck_from_z_m(j, (z_a, z_b, z_c), circuit, collect_artifacts, diagnostics.timer)
+                }
+            };
+
+            instance_lhs += &(&rowcheck * instance_combiner);
+
+            let (h_0_i, remainder) = RoundTimer::time(diagnostics.timer, RoundPhase::Selector, || {
+                apply_randomized_selector(
+                    &mut instance_lhs,
+                    circuit_combiner,
+                    &max_constraint_domain,
+                    &constraint_domain,
+                    false,
+                )
+            })?;
+            if remainder.is_some() {
+                return Err(ProverError::NonDivisible(format!(
+                    "The rowcheck for instance {j} of circuit {}",
+                    circuit.id
+                )));
+            }
+            Ok((h_0_i, artifacts))
+        });
+        std::panic::catch_unwind(job).unwrap_or_else(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(anyhow!("Rowcheck for instance {j} of circuit {} panicked: {message}", circuit.id).into())
+        })
+    }
+
+    /// Computes `z_a * z_b - z_c` for the `j`-th instance of `circuit`,
+    /// by interpolating each `z_m` over the `constraint_domain` and computing the product via FFTs.
+    /// If `collect_artifacts` is set, the interpolated `z_m` polynomials are also returned, keyed by their label.
+    fn calculate_rowcheck(
+        j: usize,
+        (z_a, z_b, z_c): (Vec<F>, Vec<F>, Vec<F>),
+        constraint_domain: EvaluationDomain<F>,
+        circuit: &Circuit<F, SM>,
+        collect_artifacts: bool,
+        diagnostics: RoundDiagnostics<'_>,
+    ) -> Result<(DensePolynomial<F>, Vec<(String, DensePolynomial<F>)>)>
+    where
+        F: prover::WitnessField,
+    {
+        let za_label = witness_label(circuit.id, "z_a", j);
+        let zb_label = witness_label(circuit.id, "z_b", j);
+        let zc_label = witness_label(circuit.id, "z_c", j);
+
+        let z_a = Self::calculate_z_m(za_label, z_a, constraint_domain, circuit, diagnostics)?;
+        let z_b = Self::calculate_z_m(zb_label, z_b, constraint_domain, circuit, diagnostics)?;
+        let z_c = Self::calculate_z_m(zc_label, z_c, constraint_domain, circuit, diagnostics)?;
+        Ok(Self::calculate_rowcheck_from_z_m(j, (z_a, z_b, z_c), circuit, collect_artifacts, diagnostics.timer))
+    }
+
+    /// Computes `z_a * z_b - z_c` via FFTs, for the interpolated `z_m` polynomials of the `j`-th instance of `circuit`.
+    /// If `collect_artifacts` is set, the `z_m` polynomials are also returned, keyed by their label.
+    fn calculate_rowcheck_from_z_m(
+        j: usize,
+        (z_a, z_b, z_c): (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>),
+        circuit: &Circuit<F, SM>,
+        collect_artifacts: bool,
+        timer: Option<&RoundTimer>,
+    ) -> (DensePolynomial<F>, Vec<(String, DensePolynomial<F>)>)
+    where
+        F: prover::WitnessField,
+    {
+        let rowcheck = RoundTimer::time(timer, RoundPhase::Multiplication, || {
+            let mut multiplier_2 = PolyMultiplier::new();
+            multiplier_2.add_precomputation(&circuit.fft_precomputation, &circuit.ifft_precomputation);
+            multiplier_2.add_polynomial_ref(&z_a, "z_a");
+            multiplier_2.add_polynomial_ref(&z_b, "z_b");
+            let mut rowcheck = multiplier_2.multiply().unwrap();
+            cfg_iter_mut!(rowcheck.coeffs).zip(&z_c.coeffs).for_each(|(ab, c)| *ab -= c);
+            rowcheck
+        });
+        let artifacts = match collect_artifacts {
+            true => vec![
+                (witness_label(circuit.id, "z_a", j), z_a),
+                (witness_label(circuit.id, "z_b", j), z_b),
+                (witness_label(circuit.id, "z_c", j), z_c),
+            ],
+            false => {
+                let (mut z_a, mut z_b, mut z_c) = (z_a, z_b, z_c);
+                clear_witness(&mut z_a.coeffs);
+                clear_witness(&mut z_b.coeffs);
+                clear_witness(&mut z_c.coeffs);
+                vec![]
+            }
+        };
+        (rowcheck, artifacts)
+    }
+
+    pub(in crate::snark::varuna) fn calculate_z_m(
         label: impl ToString,
         evaluations: Vec<F>,
         constraint_domain: EvaluationDomain<F>,
         circuit: &Circuit<F, SM>,
-    ) -> DensePolynomial<F> {
+        diagnostics: RoundDiagnostics<'_>,
+    ) -> Result<DensePolynomial<F>>
+    where
+        F: prover::WitnessField,
+    {
+        Self::calculate_z_m_with_pc(label, evaluations, constraint_domain, &circuit.ifft_precomputation, diagnostics)
+    }
+
+    /// Interpolates the `z_m` evaluations of several instances of `circuit` over their shared `constraint_domain`.
+    /// This derives the IFFT precomputation for the `constraint_domain` once, instead of once per instance.
+    /// The outputs match those of [`Self::calculate_z_m`] on each instance.
+    pub(in crate::snark::varuna) fn calculate_z_m_batch(
+        labels: Vec<String>,
+        evaluations_per_instance: Vec<Vec<F>>,
+        constraint_domain: EvaluationDomain<F>,
+        circuit: &Circuit<F, SM>,
+        diagnostics: RoundDiagnostics<'_>,
+    ) -> Result<Vec<DensePolynomial<F>>>
+    where
+        F: prover::WitnessField,
+    {
+        ensure!(
+            labels.len() == evaluations_per_instance.len(),
+            "Expected one label per instance, found {} labels for {} instances",
+            labels.len(),
+            evaluations_per_instance.len()
+        );
+        let batch_time = start_timer!(|| format!("Computing {} z_m polynomials", labels.len()));
+
+        let ifft_precomputation =
+            circuit.ifft_precomputation.precomputation_for_subdomain(&constraint_domain).ok_or_else(|| {
+                anyhow!("The constraint domain exceeds the IFFT precomputation of circuit {}", circuit.id)
+            })?;
+        let polys = cfg_into_iter!(labels)
+            .zip(evaluations_per_instance)
+            .map(|(label, evaluations)| {
+                Self::calculate_z_m_with_pc(label, evaluations, constraint_domain, &ifft_precomputation, diagnostics)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        end_timer!(batch_time);
+
+        Ok(polys)
+    }
+
+    /// Interpolates the `z_m` evaluations over the `constraint_domain`, using the given IFFT precomputation.
+    /// The evaluations are zero-padded to the size of the domain, and rejected if there are more than that.
+    /// If `diagnostics.verify_interpolations` is set, the interpolation is checked against the evaluations
+    /// in any build, and a mismatch is returned as an error.
+    fn calculate_z_m_with_pc(
+        label: impl ToString,
+        mut evaluations: Vec<F>,
+        constraint_domain: EvaluationDomain<F>,
+        ifft_precomputation: &IFFTPrecomputation<F>,
+        diagnostics: RoundDiagnostics<'_>,
+    ) -> Result<DensePolynomial<F>>
+    where
+        F: prover::WitnessField,
+    {
         let label = label.to_string();
         let poly_time = start_timer!(|| format!("Computing {label}"));
 
-        let evals = EvaluationsOnDomain::from_vec_and_domain(evaluations, constraint_domain);
-        let poly = evals.interpolate_with_pc_by_ref(&circuit.ifft_precomputation);
+        // Padding the witness in place may reallocate it, which would leave the old buffer behind uncleared.
+        // Instead, the witness is copied into a buffer of the full size, and then cleared.
+        if evaluations.len() < constraint_domain.size() {
+            let mut padded = Vec::with_capacity(constraint_domain.size());
+            padded.extend_from_slice(&evaluations);
+            padded.resize(constraint_domain.size(), F::zero());
+            clear_witness(&mut evaluations);
+            evaluations = padded;
+        }
+        // Excess evaluations are not padded, and are rejected here.
+        let mut evals = EvaluationsOnDomain::try_from_vec_and_domain(evaluations, constraint_domain)
+            .map_err(|error| error.context(format!("Invalid evaluations for {label}")))?;
+        let poly = RoundTimer::time(diagnostics.timer, RoundPhase::Interpolation, || {
+            evals.interpolate_with_pc_by_ref(ifft_precomputation)
+        });
 
-        debug_assert!(
-            poly.evaluate_over_domain_by_ref(constraint_domain)
-                .evaluations
-                .into_iter()
-                .zip(&evals.evaluations)
-                .all(|(z, e)| *e == z),
-            "Label: {label}\n1: {:#?}\n2: {:#?}",
-            poly.evaluate_over_domain_by_ref(constraint_domain).evaluations,
-            &evals.evaluations,
-        );
+        // Re-evaluating the interpolation over the whole domain is costly, so it can be disabled in debug builds.
+        let assert_interpolations = cfg!(all(debug_assertions, feature = "verify_fft"));
+        let verification = (assert_interpolations || diagnostics.verify_interpolations)
+            .then(|| Self::verify_interpolation(&label, &poly, &evals.evaluations, constraint_domain));
+        clear_witness(&mut evals.evaluations);
+        if let Some(Err(error)) = verification {
+            if assert_interpolations {
+                panic!("{error}");
+            }
+            return Err(error.into());
+        }
+        if let Some(histogram) = diagnostics.degree_histogram {
+            histogram.record(poly.degree());
+        }
 
         end_timer!(poly_time);
 
-        poly
+        Ok(poly)
+    }
+
+    /// Checks that `poly` evaluates to `evaluations` over the `domain`, i.e. that it is their interpolation.
+    pub(in crate::snark::varuna) fn verify_interpolation(
+        label: &str,
+        poly: &DensePolynomial<F>,
+        evaluations: &[F],
+        domain: EvaluationDomain<F>,
+    ) -> Result<(), AHPError>
+    where
+        F: prover::WitnessField,
+    {
+        let mut reevaluations = poly.evaluate_over_domain_by_ref(domain).evaluations;
+        let matches = reevaluations == evaluations;
+        clear_witness(&mut reevaluations);
+        match matches {
+            true => Ok(()),
+            false => Err(AHPError::InterpolationMismatch(label.to_string())),
+        }
+    }
+
+    /// Flags an `h_0` whose degree is far below its expected degree, as this indicates a collapsed rowcheck
+    /// (e.g. all-zero witnesses). The second round fails on this in debug builds, and records it as a warning
+    /// in the prover state otherwise.
+    #[cfg(feature = "strict_degree_checks")]
+    pub(in crate::snark::varuna) fn check_h_0_degree(
+        h_0: &DensePolynomial<F>,
+        max_constraint_domain: EvaluationDomain<F>,
+    ) -> Result<(), ProverError> {
+        let expected_degree = Self::h_0_expected_degree(max_constraint_domain.size());
+        if h_0.degree() < expected_degree / 2 {
+            return Err(ProverError::DegreeTooLow("h_0".to_string(), h_0.degree(), expected_degree));
+        }
+        Ok(())
+    }
+
+    /// The degree of `h_0` is only checked against its expected degree with the `strict_degree_checks` feature.
+    #[cfg(not(feature = "strict_degree_checks"))]
+    pub(in crate::snark::varuna) fn check_h_0_degree(
+        _h_0: &DensePolynomial<F>,
+        _max_constraint_domain: EvaluationDomain<F>,
+    ) -> Result<(), ProverError> {
+        Ok(())
     }
 }
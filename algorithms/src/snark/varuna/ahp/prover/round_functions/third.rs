@@ -71,7 +71,11 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         verifier_second_message: &verifier::SecondMessage<F>,
         mut state: prover::State<'a, F, SM>,
         _r: &mut R,
-    ) -> Result<(prover::ThirdMessage<F>, prover::ThirdOracles<F>, prover::State<'a, F, SM>), AHPError> {
+    ) -> Result<(prover::ThirdMessage<F>, prover::ThirdOracles<F>, prover::State<'a, F, SM>), AHPError>
+    where
+        F: prover::WitnessField,
+    {
+        state.enter_round(prover::RoundStage::Third)?;
         let round_time = start_timer!(|| "AHP::Prover::ThirdRound");
 
         let zk_bound = Self::zk_bound();
@@ -109,13 +113,16 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         drop(x_g_1_sum); // Be assured we don't use x_g_1_sum anymore
 
         assert!(g_1.degree() <= max_variable_domain.size() - 2);
-        assert!(h_1.degree() <= 2 * max_variable_domain.size() + 2 * zk_bound.unwrap_or(0) - 2);
+        assert!(h_1.degree() <= Self::h_1_degree_bound(max_variable_domain.size()));
 
         let oracles = prover::ThirdOracles {
             g_1: LabeledPolynomial::new("g_1", g_1, max_variable_domain.size() - 2, zk_bound),
             h_1: LabeledPolynomial::new("h_1", h_1, None, None),
         };
-        assert!(oracles.matches_info(&Self::third_round_polynomial_info(state.max_variable_domain.size())));
+        let mismatches = oracles.info_mismatches(&Self::third_round_polynomial_info(state.max_variable_domain.size()));
+        assert!(mismatches.is_empty(), "The third round oracles do not match their polynomial info: {mismatches:?}");
+        state.record_degrees(oracles.iter());
+        state.absorb_round_outputs(&[*alpha, *eta_b, *eta_c], oracles.iter())?;
 
         end_timer!(round_time);
 
@@ -130,7 +137,10 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         alpha: &F,
         eta_b: &F,
         eta_c: &F,
-    ) -> Result<(DensePolynomial<F>, DensePolynomial<F>, ThirdMessage<F>)> {
+    ) -> Result<(DensePolynomial<F>, DensePolynomial<F>, ThirdMessage<F>)>
+    where
+        F: prover::WitnessField,
+    {
         let num_instances = batch_combiners.values().map(|c| c.instance_combiners.len()).collect_vec();
         let total_instances = num_instances.iter().sum::<usize>();
         let max_variable_domain = &state.max_variable_domain;
@@ -218,14 +228,17 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
     pub(in crate::snark::varuna) fn calculate_assignments(
         state: &mut prover::State<F, SM>,
-    ) -> Result<BTreeMap<CircuitId, Vec<DensePolynomial<F>>>> {
+    ) -> Result<BTreeMap<CircuitId, Vec<DensePolynomial<F>>>>
+    where
+        F: prover::WitnessField,
+    {
         let assignments_time = start_timer!(|| "Calculate assignments");
         let assignments: BTreeMap<_, _> = state
             .circuit_specific_states
             .iter()
             .zip_eq(state.first_round_oracles.as_ref().unwrap().batches.values())
             .map(|((circuit, circuit_specific_state), w_polys)| {
-                let x_polys = &circuit_specific_state.x_polys;
+                let x_polys = circuit_specific_state.x_polys();
                 let input_domain = &circuit_specific_state.input_domain;
                 let assignments_i: Vec<_> = cfg_iter!(w_polys)
                     .zip_eq(x_polys)
@@ -249,7 +262,10 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
     fn calculate_matrix_transpose(
         state: &mut prover::State<F, SM>,
-    ) -> Result<BTreeMap<CircuitId, BTreeMap<String, Matrix<F>>>> {
+    ) -> Result<BTreeMap<CircuitId, BTreeMap<String, Matrix<F>>>>
+    where
+        F: prover::WitnessField,
+    {
         let transpose_time = start_timer!(|| "Transpose of matrices");
         let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len() * 3);
         state.circuit_specific_states.iter().for_each(|(circuit, circuit_specific_state)| {
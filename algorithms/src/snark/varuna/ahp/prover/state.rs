@@ -12,16 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    ops::RangeInclusive,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
     polycommit::sonic_pc::LabeledPolynomial,
-    r1cs::{SynthesisError, SynthesisResult},
-    snark::varuna::{AHPError, AHPForR1CS, Circuit, SNARKMode},
+    r1cs::SynthesisResult,
+    snark::varuna::{verifier, AHPError, AHPForR1CS, Circuit, ProverError, SNARKMode},
 };
-use anyhow::anyhow;
+use anyhow::{anyhow, bail, ensure};
+use parking_lot::Mutex;
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{error, serialize::*, FromBytes, Read, ToBytes, Write};
+#[cfg(feature = "zeroize_witnesses")]
+use zeroize::Zeroize;
 
 /// Circuit Specific State of the Prover
 pub struct CircuitSpecificState<F: PrimeField> {
@@ -67,15 +77,280 @@ pub struct CircuitSpecificState<F: PrimeField> {
 
     /// Intermediary polynomials of the matrix sumcheck.
     pub(super) lhs_polynomials: Option<[DensePolynomial<F>; 3]>,
+
+    /// The latest round whose artifacts have been dropped, if any.
+    artifacts_dropped: Option<RoundStage>,
+}
+
+impl<F: PrimeField> CircuitSpecificState<F> {
+    /// Takes the private variables, which are consumed by the first round.
+    pub(super) fn take_private_variables(&mut self) -> Vec<Vec<F>> {
+        self.debug_assert_not_dropped("the private variables", RoundStage::First);
+        core::mem::take(&mut self.private_variables)
+    }
+
+    /// Takes the `z_a`, `z_b`, and `z_c` vectors, which are consumed by the second round.
+    pub(super) fn take_z_m(&mut self) -> (Option<Vec<Vec<F>>>, Option<Vec<Vec<F>>>, Option<Vec<Vec<F>>>) {
+        self.debug_assert_not_dropped("the z_a, z_b, and z_c vectors", RoundStage::Second);
+        (self.z_a.take(), self.z_b.take(), self.z_c.take())
+    }
+
+    /// Returns the public input polynomials, which are last read by the third round.
+    pub(super) fn x_polys(&self) -> &[DensePolynomial<F>] {
+        self.debug_assert_not_dropped("the public input polynomials", RoundStage::Third);
+        &self.x_polys
+    }
+
+    /// Asserts, in debug builds, that a buffer last read by `last_round` has not been dropped.
+    fn debug_assert_not_dropped(&self, buffer: &str, last_round: RoundStage) {
+        debug_assert!(
+            self.artifacts_dropped.map_or(true, |dropped| dropped < last_round),
+            "Read {buffer} after dropping the artifacts of {}",
+            self.artifacts_dropped.unwrap()
+        );
+    }
+}
+
+/// The interpolated `z_a`, `z_b`, and `z_c` polynomials of each instance, keyed by their witness label.
+/// These are only collected if enabled via [`State::enable_witness_artifacts`], for auditing purposes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct WitnessArtifacts<F: WitnessField> {
+    pub polynomials: BTreeMap<String, DensePolynomial<F>>,
+}
+
+#[cfg(feature = "zeroize_witnesses")]
+impl<F: WitnessField> Zeroize for WitnessArtifacts<F> {
+    fn zeroize(&mut self) {
+        self.polynomials.values_mut().for_each(|polynomial| polynomial.coeffs.zeroize());
+    }
+}
+
+impl<F: WitnessField> Drop for WitnessArtifacts<F> {
+    fn drop(&mut self) {
+        #[cfg(feature = "zeroize_witnesses")]
+        self.zeroize();
+    }
+}
+
+/// The field of the witnesses handled by the prover.
+/// With the `zeroize_witnesses` feature, the witnesses are zeroized once consumed, so the field must be `Zeroize`.
+#[cfg(feature = "zeroize_witnesses")]
+pub trait WitnessField: PrimeField + Zeroize {}
+
+#[cfg(feature = "zeroize_witnesses")]
+impl<F: PrimeField + Zeroize> WitnessField for F {}
+
+/// The field of the witnesses handled by the prover.
+/// With the `zeroize_witnesses` feature, the witnesses are zeroized once consumed, so the field must be `Zeroize`.
+#[cfg(not(feature = "zeroize_witnesses"))]
+pub trait WitnessField: PrimeField {}
+
+#[cfg(not(feature = "zeroize_witnesses"))]
+impl<F: PrimeField> WitnessField for F {}
+
+/// Clears the given witness data, overwriting it with zeros first so that private assignments do not linger
+/// in freed memory.
+#[cfg(feature = "zeroize_witnesses")]
+pub(in crate::snark) fn clear_witness<Z: Zeroize + Default>(witness: &mut Z) {
+    witness.zeroize();
+    *witness = Z::default();
+}
+
+/// Clears the given witness data. It is only overwritten with zeros with the `zeroize_witnesses` feature.
+#[cfg(not(feature = "zeroize_witnesses"))]
+pub(in crate::snark) fn clear_witness<Z: Default>(witness: &mut Z) {
+    *witness = Z::default();
+}
+
+impl<F: WitnessField> ToBytes for WitnessArtifacts<F> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut w)
+            .map_err(|_| error("Could not serialize WitnessArtifacts"))
+    }
+}
+
+impl<F: WitnessField> FromBytes for WitnessArtifacts<F> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut r)
+            .map_err(|_| error("Could not deserialize WitnessArtifacts"))
+    }
+}
+
+/// A histogram of the degrees of the polynomials constructed by the prover, bucketed by powers of two.
+/// This is only collected if enabled via [`State::enable_degree_histogram`] or the `degree_histogram` feature.
+#[derive(Debug, Default)]
+pub struct DegreeHistogram {
+    /// The number of polynomials in each bucket, where bucket `k > 0` holds the degrees in `[2^(k-1), 2^k)`,
+    /// and bucket `0` holds the degree `0`.
+    buckets: Mutex<BTreeMap<u32, usize>>,
+}
+
+impl DegreeHistogram {
+    /// Returns the bucket of the given degree.
+    const fn bucket(degree: usize) -> u32 {
+        usize::BITS - degree.leading_zeros()
+    }
+
+    /// Returns the inclusive range of degrees held by the given bucket.
+    const fn bucket_range(bucket: u32) -> RangeInclusive<usize> {
+        match bucket {
+            0 => 0..=0,
+            _ => (1 << (bucket - 1))..=(usize::MAX >> (usize::BITS - bucket)),
+        }
+    }
+
+    /// Records a polynomial of the given degree.
+    pub fn record(&self, degree: usize) {
+        *self.buckets.lock().entry(Self::bucket(degree)).or_default() += 1;
+    }
+
+    /// Records the degrees of the given polynomials.
+    pub fn record_all<'p, F: PrimeField>(&self, polynomials: impl IntoIterator<Item = &'p LabeledPolynomial<F>>) {
+        polynomials.into_iter().for_each(|polynomial| self.record(polynomial.polynomial().degree()));
+    }
+
+    /// Returns the number of polynomials recorded.
+    pub fn total(&self) -> usize {
+        self.buckets.lock().values().sum()
+    }
+
+    /// Returns the number of polynomials recorded in each non-empty bucket, keyed by the range of degrees it holds.
+    pub fn buckets(&self) -> Vec<(RangeInclusive<usize>, usize)> {
+        self.buckets.lock().iter().map(|(bucket, count)| (Self::bucket_range(*bucket), *count)).collect()
+    }
+}
+
+impl fmt::Display for DegreeHistogram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Polynomial degrees ({} polynomials):", self.total())?;
+        for (range, count) in self.buckets() {
+            write!(f, "\n  {:>10} ..= {:<10} {count}", range.start(), range.end())?;
+        }
+        Ok(())
+    }
+}
+
+/// The prover rounds, in the order in which they must be run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RoundStage {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+    /// All rounds have been run.
+    Done,
+}
+
+impl RoundStage {
+    /// Returns the stage that follows `self`.
+    const fn next(self) -> Self {
+        match self {
+            Self::First => Self::Second,
+            Self::Second => Self::Third,
+            Self::Third => Self::Fourth,
+            Self::Fourth => Self::Fifth,
+            Self::Fifth | Self::Done => Self::Done,
+        }
+    }
+}
+
+impl fmt::Display for RoundStage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::First => write!(f, "the first round"),
+            Self::Second => write!(f, "the second round"),
+            Self::Third => write!(f, "the third round"),
+            Self::Fourth => write!(f, "the fourth round"),
+            Self::Fifth => write!(f, "the fifth round"),
+            Self::Done => write!(f, "no further round"),
+        }
+    }
+}
+
+/// Runtime options for the AHP prover, set via [`State::set_config`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProverConfig {
+    /// Whether to check each `z_m` interpolation against its evaluations, independently of `debug_assertions`.
+    /// A mismatch indicates a corrupted FFT, and aborts the proof with [`AHPError::InterpolationMismatch`].
+    pub verify_interpolations: bool,
+    /// Whether to cover the verifier messages and the oracles of each round in [`State::state_hash`].
+    /// This hashes every oracle as it is constructed, so it is off by default.
+    pub hash_round_outputs: bool,
+}
+
+/// The largest domains of a batch of circuits, as computed by [`State::compute_max_domains`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MaxDomains<F: PrimeField> {
+    /// The largest constraint domain of all circuits in the batch.
+    pub max_constraint_domain: EvaluationDomain<F>,
+    /// The largest variable domain of all circuits in the batch.
+    pub max_variable_domain: EvaluationDomain<F>,
+    /// The largest non_zero domain of all circuits in the batch.
+    pub max_non_zero_domain: EvaluationDomain<F>,
+}
+
+/// The time spent in each phase of the second round of the AHP prover.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoundTiming {
+    /// The time spent interpolating the `z_m` polynomials and the pointwise rowcheck.
+    pub interpolation: Duration,
+    /// The time spent multiplying `z_a` by `z_b` and subtracting `z_c`.
+    pub multiplication: Duration,
+    /// The time spent dividing each rowcheck by the vanishing polynomial of its constraint domain.
+    pub selector: Duration,
+    /// The time spent summing the per-instance quotients into `h_0`.
+    pub h_sum: Duration,
+}
+
+/// A timed phase of the second round of the AHP prover.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(in crate::snark) enum RoundPhase {
+    Interpolation,
+    Multiplication,
+    Selector,
+    HSum,
+}
+
+impl RoundTiming {
+    fn phase_mut(&mut self, phase: RoundPhase) -> &mut Duration {
+        match phase {
+            RoundPhase::Interpolation => &mut self.interpolation,
+            RoundPhase::Multiplication => &mut self.multiplication,
+            RoundPhase::Selector => &mut self.selector,
+            RoundPhase::HSum => &mut self.h_sum,
+        }
+    }
+}
+
+/// Accumulates the [`RoundTiming`] of a round across its parallel jobs.
+/// This is only collected by [`AHPForR1CS::prover_second_round_timed`].
+#[derive(Debug, Default)]
+pub(in crate::snark) struct RoundTimer(Mutex<RoundTiming>);
+
+impl RoundTimer {
+    /// Runs `f`, adding its duration to `phase` if a timer is given.
+    pub(in crate::snark) fn time<T>(timer: Option<&Self>, phase: RoundPhase, f: impl FnOnce() -> T) -> T {
+        let Some(timer) = timer else { return f() };
+        let start = Instant::now();
+        let result = f();
+        *timer.0.lock().phase_mut(phase) += start.elapsed();
+        result
+    }
+
+    /// Returns the accumulated timing.
+    pub(in crate::snark) fn into_timing(self) -> RoundTiming {
+        self.0.into_inner()
+    }
 }
 
 /// State for the AHP prover.
-pub struct State<'a, F: PrimeField, SM: SNARKMode> {
+pub struct State<'a, F: WitnessField, SM: SNARKMode> {
     /// The state for each circuit in the batch.
     pub(in crate::snark) circuit_specific_states: BTreeMap<&'a Circuit<F, SM>, CircuitSpecificState<F>>,
     /// The first round oracles sent by the prover.
     /// The length of this list must be equal to the batch size.
-    pub(in crate::snark) first_round_oracles: Option<super::FirstOracles<F>>,
+    pub(in crate::snark) first_round_oracles: Option<Arc<super::FirstOracles<F>>>,
     /// The largest non_zero domain of all circuits in the batch.
     pub(in crate::snark) max_non_zero_domain: EvaluationDomain<F>,
     /// The largest constraint domain of all circuits in the batch.
@@ -84,6 +359,20 @@ pub struct State<'a, F: PrimeField, SM: SNARKMode> {
     pub(in crate::snark) max_variable_domain: EvaluationDomain<F>,
     /// The total number of instances we're proving in the batch.
     pub(in crate::snark) total_instances: usize,
+    /// The witness polynomials collected for auditing, if enabled.
+    pub(in crate::snark) witness_artifacts: Option<WitnessArtifacts<F>>,
+    /// The degrees of the constructed polynomials, if enabled.
+    pub(in crate::snark) degree_histogram: Option<DegreeHistogram>,
+    /// The runtime options of the prover.
+    pub(in crate::snark) config: ProverConfig,
+    /// The timer of the phases of the current round, if enabled.
+    pub(in crate::snark) round_timer: Option<RoundTimer>,
+    /// The next round to be run.
+    pub(in crate::snark) stage: RoundStage,
+    /// The non-fatal errors found by the rounds run so far.
+    pub(in crate::snark) warnings: Vec<ProverError>,
+    /// The hash of the verifier messages and oracles of the rounds run so far, if enabled.
+    round_outputs: blake2::Blake2s256,
 }
 
 /// The public inputs for a single instance.
@@ -105,33 +394,47 @@ pub(super) struct Assignments<F>(
     pub(super) Zc<F>,
 );
 
-impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
-    pub(super) fn initialize(
-        indices_and_assignments: BTreeMap<&'a Circuit<F, SM>, Vec<Assignments<F>>>,
-    ) -> Result<Self, AHPError> {
+impl<'a, F: WitnessField, SM: SNARKMode> State<'a, F, SM> {
+    /// Computes the largest constraint, variable, and non_zero domains across the given batch of circuits.
+    /// The constraint and variable domains are sized for the largest circuit, and so are at least as large as
+    /// the domain of every circuit in the batch.
+    pub fn compute_max_domains(
+        circuits: impl IntoIterator<Item = &'a Circuit<F, SM>>,
+    ) -> Result<MaxDomains<F>, AHPError> {
         let mut max_non_zero_domain: Option<EvaluationDomain<F>> = None;
         let mut max_num_constraints = 0;
         let mut max_num_variables = 0;
+        for circuit in circuits {
+            let index_info = &circuit.index_info;
+            max_num_constraints = max_num_constraints.max(index_info.num_constraints);
+            max_num_variables = max_num_variables.max(index_info.num_public_and_private_variables);
+            let non_zero_domains = AHPForR1CS::<_, SM>::cmp_non_zero_domains(index_info, max_non_zero_domain)?;
+            max_non_zero_domain = non_zero_domains.max_non_zero_domain;
+        }
+
+        let max_non_zero_domain = max_non_zero_domain.ok_or(AHPError::BatchSizeIsZero)?;
+        let max_constraint_domain = EvaluationDomain::new_checked(max_num_constraints)?;
+        let max_variable_domain = EvaluationDomain::new_checked(max_num_variables)?;
+        Ok(MaxDomains { max_constraint_domain, max_variable_domain, max_non_zero_domain })
+    }
+
+    pub(super) fn initialize(
+        indices_and_assignments: BTreeMap<&'a Circuit<F, SM>, Vec<Assignments<F>>>,
+    ) -> Result<Self, AHPError> {
+        let MaxDomains { max_constraint_domain, max_variable_domain, max_non_zero_domain } =
+            Self::compute_max_domains(indices_and_assignments.keys().copied())?;
         let mut total_instances = 0usize;
         let circuit_specific_states = indices_and_assignments
             .into_iter()
             .map(|(circuit, variable_assignments)| {
                 let index_info = &circuit.index_info;
 
-                let constraint_domain =
-                    EvaluationDomain::new(index_info.num_constraints).ok_or(SynthesisError::PolyTooLarge)?;
-                max_num_constraints = max_num_constraints.max(index_info.num_constraints);
-
-                let variable_domain = EvaluationDomain::new(index_info.num_public_and_private_variables)
-                    .ok_or(SynthesisError::PolyTooLarge)?;
-                max_num_variables = max_num_variables.max(index_info.num_public_and_private_variables);
-
-                let non_zero_domains = AHPForR1CS::<_, SM>::cmp_non_zero_domains(index_info, max_non_zero_domain)?;
-                max_non_zero_domain = non_zero_domains.max_non_zero_domain;
+                let constraint_domain = EvaluationDomain::new_checked(index_info.num_constraints)?;
+                let variable_domain = EvaluationDomain::new_checked(index_info.num_public_and_private_variables)?;
+                let non_zero_domains = AHPForR1CS::<_, SM>::cmp_non_zero_domains(index_info, None)?;
 
                 let first_padded_public_inputs = &variable_assignments[0].0;
-                let input_domain = EvaluationDomain::new(first_padded_public_inputs.len())
-                    .ok_or(anyhow!("Cannot create EvaluationDomain"))?;
+                let input_domain = EvaluationDomain::new_checked(first_padded_public_inputs.len())?;
                 let batch_size = variable_assignments.len();
                 total_instances =
                     total_instances.checked_add(batch_size).ok_or_else(|| anyhow!("Batch size too large"))?;
@@ -170,15 +473,12 @@ impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
                     a_polys: None,
                     b_polys: None,
                     lhs_polynomials: None,
+                    artifacts_dropped: None,
                 };
                 Ok((circuit, state))
             })
             .collect::<SynthesisResult<BTreeMap<_, _>>>()?;
 
-        let max_non_zero_domain = max_non_zero_domain.ok_or(AHPError::BatchSizeIsZero)?;
-        let max_constraint_domain = EvaluationDomain::new(max_num_constraints).ok_or(SynthesisError::PolyTooLarge)?;
-        let max_variable_domain = EvaluationDomain::new(max_num_variables).ok_or(SynthesisError::PolyTooLarge)?;
-
         Ok(Self {
             max_constraint_domain,
             max_variable_domain,
@@ -186,14 +486,107 @@ impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
             circuit_specific_states,
             total_instances,
             first_round_oracles: None,
+            witness_artifacts: None,
+            degree_histogram: cfg!(feature = "degree_histogram").then(Default::default),
+            config: ProverConfig::default(),
+            round_timer: None,
+            stage: RoundStage::First,
+            warnings: Vec::new(),
+            round_outputs: Default::default(),
         })
     }
 
+    /// Get the next round to be run.
+    pub fn stage(&self) -> RoundStage {
+        self.stage
+    }
+
+    /// Ensures that `round` is the next round to be run, and advances the state past it.
+    pub(super) fn enter_round(&mut self, round: RoundStage) -> Result<(), ProverError> {
+        if self.stage != round {
+            return Err(ProverError::OutOfOrderRound { expected: self.stage, got: round });
+        }
+        self.stage = self.stage.next();
+        Ok(())
+    }
+
+    /// Frees the buffers that no round after `round` reads, to reduce the memory footprint of long proofs.
+    /// This includes the buffers freed by earlier rounds, and may only be called once `round` has been run.
+    /// In debug builds, reading a dropped buffer through its accessor panics.
+    pub fn drop_round_artifacts(&mut self, round: RoundStage) -> Result<(), ProverError> {
+        if self.stage <= round {
+            return Err(anyhow!("Cannot drop the artifacts of {round} before it has been run").into());
+        }
+        for circuit_state in self.circuit_specific_states.values_mut() {
+            // The private variables are consumed by the first round.
+            clear_witness(&mut circuit_state.private_variables);
+            // The `z_a`, `z_b`, and `z_c` vectors are consumed by the second round.
+            if round >= RoundStage::Second {
+                clear_witness(&mut circuit_state.z_a);
+                clear_witness(&mut circuit_state.z_b);
+                clear_witness(&mut circuit_state.z_c);
+            }
+            // The public input polynomials are read by the first round, and last read by the third round.
+            if round >= RoundStage::Third {
+                circuit_state.x_polys = Vec::new();
+            }
+            circuit_state.artifacts_dropped = circuit_state.artifacts_dropped.max(Some(round));
+        }
+        Ok(())
+    }
+
     /// Get the batch size for a given circuit.
     pub fn batch_size(&self, circuit: &Circuit<F, SM>) -> Option<usize> {
         self.circuit_specific_states.get(circuit).map(|s| s.batch_size)
     }
 
+    /// Get the largest constraint domain of all circuits in the batch.
+    pub fn max_constraint_domain(&self) -> EvaluationDomain<F> {
+        self.max_constraint_domain
+    }
+
+    /// Get the largest variable domain of all circuits in the batch.
+    pub fn max_variable_domain(&self) -> EvaluationDomain<F> {
+        self.max_variable_domain
+    }
+
+    /// Get the largest non-zero domain of all circuits in the batch.
+    pub fn max_non_zero_domain(&self) -> EvaluationDomain<F> {
+        self.max_non_zero_domain
+    }
+
+    /// Hashes the domains and the `z_a`, `z_b`, and `z_c` vectors of every circuit in the batch,
+    /// so that the states of two prover runs can be compared after each round.
+    /// The circuits are hashed in the order of their IDs, so the hash is independent of how the batch was assembled.
+    /// With [`ProverConfig::hash_round_outputs`], the verifier messages and the oracles of the rounds run so far
+    /// are covered as well. Otherwise, the hash only changes once the second round consumes the witnesses.
+    pub fn state_hash(&self) -> Result<F, SerializationError> {
+        use blake2::Digest;
+
+        let mut blake2 = blake2::Blake2s256::new();
+        for (circuit, state) in &self.circuit_specific_states {
+            blake2.update(circuit.id.0);
+            for domain in [
+                state.input_domain,
+                state.variable_domain,
+                state.constraint_domain,
+                state.non_zero_a_domain,
+                state.non_zero_b_domain,
+                state.non_zero_c_domain,
+            ] {
+                domain.serialize_uncompressed(&mut blake2)?;
+            }
+            (state.batch_size as u64).serialize_uncompressed(&mut blake2)?;
+            state.z_a.serialize_uncompressed(&mut blake2)?;
+            state.z_b.serialize_uncompressed(&mut blake2)?;
+            state.z_c.serialize_uncompressed(&mut blake2)?;
+        }
+        if self.config.hash_round_outputs {
+            blake2.update(self.round_outputs.clone().finalize());
+        }
+        Ok(F::from_bytes_le_mod_order(&blake2.finalize()))
+    }
+
     /// Get the public inputs for the entire batch.
     pub fn public_inputs(&self, circuit: &Circuit<F, SM>) -> Option<Vec<Vec<F>>> {
         // We need to export inputs as they live longer than prover_state
@@ -207,6 +600,115 @@ impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
         self.circuit_specific_states.get(circuit).map(|s| s.padded_public_variables.as_slice())
     }
 
+    /// Enables the collection of the interpolated witness polynomials in the second round.
+    /// Note: This roughly doubles the memory used by the witness polynomials.
+    pub fn enable_witness_artifacts(&mut self) {
+        self.witness_artifacts.get_or_insert_with(Default::default);
+    }
+
+    /// Ensures that the domains of this state agree with those of the given verifier state.
+    /// Returns an error naming the first mismatched domain,
+    /// e.g. `circuit {id} constraint domain size differs: prover 16 vs verifier 8`.
+    pub fn check_domains_match(&self, verifier_state: &verifier::State<F, SM>) -> anyhow::Result<()> {
+        let check = |name: &str, prover: EvaluationDomain<F>, verifier: EvaluationDomain<F>| -> anyhow::Result<()> {
+            ensure!(
+                prover.size() == verifier.size(),
+                "{name} size differs: prover {} vs verifier {}",
+                prover.size(),
+                verifier.size()
+            );
+            Ok(())
+        };
+
+        check("max constraint domain", self.max_constraint_domain, verifier_state.max_constraint_domain)?;
+        check("max variable domain", self.max_variable_domain, verifier_state.max_variable_domain)?;
+        check("max non-zero domain", self.max_non_zero_domain, verifier_state.max_non_zero_domain)?;
+
+        let (num_prover_circuits, num_verifier_circuits) =
+            (self.circuit_specific_states.len(), verifier_state.circuit_specific_states.len());
+        ensure!(
+            num_prover_circuits == num_verifier_circuits,
+            "circuit count differs: prover {num_prover_circuits} vs verifier {num_verifier_circuits}"
+        );
+        for (circuit, prover_state) in &self.circuit_specific_states {
+            let Some(verifier_state) = verifier_state.circuit_specific_states.get(&circuit.id) else {
+                bail!("circuit {} is missing from the verifier state", circuit.id);
+            };
+            let domains = [
+                ("input domain", prover_state.input_domain, verifier_state.input_domain),
+                ("variable domain", prover_state.variable_domain, verifier_state.variable_domain),
+                ("constraint domain", prover_state.constraint_domain, verifier_state.constraint_domain),
+                ("non-zero-a domain", prover_state.non_zero_a_domain, verifier_state.non_zero_a_domain),
+                ("non-zero-b domain", prover_state.non_zero_b_domain, verifier_state.non_zero_b_domain),
+                ("non-zero-c domain", prover_state.non_zero_c_domain, verifier_state.non_zero_c_domain),
+            ];
+            for (name, prover, verifier) in domains {
+                check(&format!("circuit {} {name}", circuit.id), prover, verifier)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the collected witness polynomials, if enabled.
+    pub fn witness_artifacts(&self) -> Option<&WitnessArtifacts<F>> {
+        self.witness_artifacts.as_ref()
+    }
+
+    /// Enables the collection of the degrees of the polynomials constructed in each round.
+    pub fn enable_degree_histogram(&mut self) {
+        self.degree_histogram.get_or_insert_with(Default::default);
+    }
+
+    /// Returns the histogram of the degrees of the constructed polynomials, if enabled.
+    pub fn degree_histogram(&self) -> Option<&DegreeHistogram> {
+        self.degree_histogram.as_ref()
+    }
+
+    /// Sets the runtime options of the prover.
+    pub fn set_config(&mut self, config: ProverConfig) {
+        self.config = config;
+    }
+
+    /// Returns the runtime options of the prover.
+    pub fn config(&self) -> &ProverConfig {
+        &self.config
+    }
+
+    /// Returns the non-fatal errors found by the rounds run so far.
+    pub fn warnings(&self) -> &[ProverError] {
+        &self.warnings
+    }
+
+    /// Absorbs the verifier message and the resulting oracles of a round into the hash of the round outputs,
+    /// if enabled via [`ProverConfig::hash_round_outputs`].
+    pub(super) fn absorb_round_outputs<'p>(
+        &mut self,
+        verifier_message: &[F],
+        oracles: impl IntoIterator<Item = &'p LabeledPolynomial<F>>,
+    ) -> Result<(), ProverError> {
+        if !self.config.hash_round_outputs {
+            return Ok(());
+        }
+        let absorb = || {
+            verifier_message.serialize_uncompressed(&mut self.round_outputs)?;
+            oracles.into_iter().try_for_each(|oracle| oracle.serialize_uncompressed(&mut self.round_outputs))
+        };
+        absorb().map_err(|error| anyhow!("Could not hash the round outputs: {error}").into())
+    }
+
+    /// Records the degrees of the given oracles, if the degree histogram is enabled.
+    pub(super) fn record_degrees<'p>(&self, oracles: impl IntoIterator<Item = &'p LabeledPolynomial<F>>) {
+        if let Some(histogram) = &self.degree_histogram {
+            histogram.record_all(oracles);
+        }
+    }
+
+    /// Returns shared access to the oracles sent by the prover in the first round, once it has been run.
+    /// The oracles of the later rounds are returned by their round functions, and are not kept in the state.
+    pub fn first_round_oracles(&self) -> Option<Arc<super::FirstOracles<F>>> {
+        self.first_round_oracles.clone()
+    }
+
     /// Iterate over the lhs_polynomials
     pub fn lhs_polys_into_iter(self) -> impl Iterator<Item = DensePolynomial<F>> + 'a {
         self.circuit_specific_states.into_values().flat_map(|s| s.lhs_polynomials.unwrap().into_iter())
@@ -17,13 +17,17 @@ use core::marker::PhantomData;
 use crate::{
     fft::EvaluationDomain,
     snark::varuna::{
-        ahp::verifier::{FirstMessage, FourthMessage, SecondMessage, ThirdMessage},
+        ahp::verifier::{BatchCombiners, FirstMessage, FourthMessage, SecondMessage, ThirdMessage},
         CircuitId,
         SNARKMode,
     },
 };
+use anyhow::{ensure, Result};
 use snarkvm_fields::PrimeField;
-use std::collections::{BTreeMap, HashSet};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Write,
+};
 
 #[derive(Debug)]
 /// Circuit Specific State of the Verifier
@@ -38,6 +42,38 @@ pub struct CircuitSpecificState<F: PrimeField> {
     /// The number of instances being proved in this batch.
     pub(in crate::snark::varuna) batch_size: usize,
 }
+
+impl<F: PrimeField> CircuitSpecificState<F> {
+    /// Initializes the state of a circuit, ensuring that its `batch_size` is nonzero
+    /// and matches the number of instance combiners in its `combiners`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        input_domain: EvaluationDomain<F>,
+        variable_domain: EvaluationDomain<F>,
+        constraint_domain: EvaluationDomain<F>,
+        non_zero_a_domain: EvaluationDomain<F>,
+        non_zero_b_domain: EvaluationDomain<F>,
+        non_zero_c_domain: EvaluationDomain<F>,
+        batch_size: usize,
+        combiners: &BatchCombiners<F>,
+    ) -> Result<Self> {
+        ensure!(batch_size > 0, "The batch size of a circuit must be nonzero");
+        ensure!(
+            batch_size == combiners.instance_combiners.len(),
+            "The batch size ({batch_size}) does not match the number of instance combiners ({})",
+            combiners.instance_combiners.len()
+        );
+        Ok(Self {
+            input_domain,
+            variable_domain,
+            constraint_domain,
+            non_zero_a_domain,
+            non_zero_b_domain,
+            non_zero_c_domain,
+            batch_size,
+        })
+    }
+}
 /// State of the AHP verifier.
 #[derive(Debug)]
 pub struct State<F: PrimeField, SM: SNARKMode> {
@@ -78,4 +114,27 @@ impl<F: PrimeField, MM: SNARKMode> State<F, MM> {
             .flat_map(|s| [s.non_zero_a_domain, s.non_zero_b_domain, s.non_zero_c_domain])
             .collect()
     }
+
+    /// Returns a description of the maximum domains and the domains of each circuit, for diagnosing domain mismatches.
+    pub fn describe_domains(&self) -> String {
+        let mut description = String::new();
+        let _ = writeln!(description, "max_constraint_domain: {}", self.max_constraint_domain.size());
+        let _ = writeln!(description, "max_variable_domain: {}", self.max_variable_domain.size());
+        let _ = writeln!(description, "max_non_zero_domain: {}", self.max_non_zero_domain.size());
+        for (circuit_id, state) in &self.circuit_specific_states {
+            let _ = writeln!(description, "circuit {circuit_id}:");
+            let domains = [
+                ("input_domain", state.input_domain),
+                ("variable_domain", state.variable_domain),
+                ("constraint_domain", state.constraint_domain),
+                ("non_zero_a_domain", state.non_zero_a_domain),
+                ("non_zero_b_domain", state.non_zero_b_domain),
+                ("non_zero_c_domain", state.non_zero_c_domain),
+            ];
+            for (name, domain) in domains {
+                let _ = writeln!(description, "  {name}: {}", domain.size());
+            }
+        }
+        description
+    }
 }
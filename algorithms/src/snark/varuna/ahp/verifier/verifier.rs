@@ -43,28 +43,12 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
         max_non_zero_domain: EvaluationDomain<TargetField>,
         fs_rng: &mut R,
     ) -> Result<(FirstMessage<TargetField>, State<TargetField, SM>)> {
-        let mut batch_combiners = BTreeMap::new();
+        let message = Self::derive_verifier_first_message(batch_sizes, fs_rng)?;
         let mut circuit_specific_states = BTreeMap::new();
-        let mut num_circuit_combiners = vec![1; batch_sizes.len()];
-        num_circuit_combiners[0] = 0; // the first circuit_combiner is TargetField::one() and needs no random sampling
 
-        for ((batch_size, (circuit_id, circuit_info)), num_c_combiner) in
-            batch_sizes.values().zip(circuit_infos).zip(num_circuit_combiners)
+        for ((batch_size, (circuit_id, circuit_info)), combiners) in
+            batch_sizes.values().zip(circuit_infos).zip(message.batch_combiners.values())
         {
-            let squeeze_time = start_timer!(|| format!("Squeezing challenges for {circuit_id}"));
-            let elems = fs_rng.squeeze_nonnative_field_elements(*batch_size - 1 + num_c_combiner);
-            end_timer!(squeeze_time);
-
-            let (instance_combiners, circuit_combiner) = elems.split_at(*batch_size - 1);
-            ensure!(circuit_combiner.len() == num_c_combiner);
-            let mut combiners =
-                BatchCombiners { circuit_combiner: TargetField::one(), instance_combiners: vec![TargetField::one()] };
-            if num_c_combiner == 1 {
-                combiners.circuit_combiner = circuit_combiner[0];
-            }
-            combiners.instance_combiners.extend(instance_combiners);
-            batch_combiners.insert(*circuit_id, combiners);
-
             let constraint_domain_time = start_timer!(|| format!("Constructing constraint domain for {circuit_id}"));
             let constraint_domain =
                 EvaluationDomain::new(circuit_info.num_constraints).ok_or(AHPError::PolyTooLarge)?;
@@ -91,20 +75,19 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
             let input_domain = EvaluationDomain::new(circuit_info.num_public_inputs).ok_or(AHPError::PolyTooLarge)?;
             end_timer!(input_domain_time);
 
-            let circuit_specific_state = CircuitSpecificState {
+            let circuit_specific_state = CircuitSpecificState::new(
                 input_domain,
                 variable_domain,
                 constraint_domain,
                 non_zero_a_domain,
                 non_zero_b_domain,
                 non_zero_c_domain,
-                batch_size: *batch_size,
-            };
+                *batch_size,
+                combiners,
+            )?;
             circuit_specific_states.insert(*circuit_id, circuit_specific_state);
         }
 
-        let message = FirstMessage { batch_combiners };
-
         let new_state = State {
             circuit_specific_states,
             max_constraint_domain,
@@ -128,15 +111,12 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
         mut state: State<TargetField, SM>,
         fs_rng: &mut R,
     ) -> Result<(SecondMessage<TargetField>, State<TargetField, SM>)> {
-        let elems = fs_rng.squeeze_nonnative_field_elements(3);
-        let (first, _) = elems.split_at(3);
-        let [alpha, eta_b, eta_c]: [_; 3] = first.try_into().map_err(anyhow::Error::msg)?;
+        let message = Self::derive_verifier_second_message(fs_rng)?;
 
         let check_vanish_poly_time = start_timer!(|| "Evaluating vanishing polynomial");
-        ensure!(!state.max_constraint_domain.evaluate_vanishing_polynomial(alpha).is_zero());
+        ensure!(!state.max_constraint_domain.evaluate_vanishing_polynomial(message.alpha).is_zero());
         end_timer!(check_vanish_poly_time);
 
-        let message = SecondMessage { alpha, eta_b, eta_c };
         state.second_round_message = Some(message);
 
         Ok((message, state))
@@ -147,11 +127,9 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
         mut state: State<TargetField, SM>,
         fs_rng: &mut R,
     ) -> Result<(ThirdMessage<TargetField>, State<TargetField, SM>)> {
-        let elems = fs_rng.squeeze_nonnative_field_elements(1);
-        let beta = elems[0];
-        ensure!(!state.max_variable_domain.evaluate_vanishing_polynomial(beta).is_zero());
+        let message = Self::derive_verifier_third_message(fs_rng);
+        ensure!(!state.max_variable_domain.evaluate_vanishing_polynomial(message.beta).is_zero());
 
-        let message = ThirdMessage { beta };
         state.third_round_message = Some(message);
 
         Ok((message, state))
@@ -162,7 +140,77 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
         mut state: State<TargetField, SM>,
         fs_rng: &mut R,
     ) -> Result<(FourthMessage<TargetField>, State<TargetField, SM>)> {
-        let num_circuits = state.circuit_specific_states.len();
+        let message = Self::derive_verifier_fourth_message(state.circuit_specific_states.len(), fs_rng);
+
+        state.fourth_round_message = Some(message.clone());
+        Ok((message, state))
+    }
+
+    /// Output the next round state.
+    pub fn verifier_fifth_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
+        mut state: State<TargetField, SM>,
+        fs_rng: &mut R,
+    ) -> Result<State<TargetField, SM>> {
+        let gamma = Self::derive_verifier_fifth_challenge(fs_rng);
+        ensure!(!state.max_non_zero_domain.evaluate_vanishing_polynomial(gamma).is_zero());
+
+        state.gamma = Some(gamma);
+        Ok(state)
+    }
+
+    /// Squeezes the batch combiners of the first verifier message from the transcript.
+    /// The first circuit combiner and the first instance combiner of each circuit are always one.
+    pub fn derive_verifier_first_message<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
+        batch_sizes: &BTreeMap<CircuitId, usize>,
+        fs_rng: &mut R,
+    ) -> Result<FirstMessage<TargetField>> {
+        let mut batch_combiners = BTreeMap::new();
+        for (i, (circuit_id, batch_size)) in batch_sizes.iter().enumerate() {
+            ensure!(*batch_size > 0, "The batch size of circuit {circuit_id} must be nonzero");
+            // The first circuit_combiner is TargetField::one() and needs no random sampling.
+            let num_c_combiner = usize::from(i > 0);
+
+            let squeeze_time = start_timer!(|| format!("Squeezing challenges for {circuit_id}"));
+            let elems = fs_rng.squeeze_nonnative_field_elements(*batch_size - 1 + num_c_combiner);
+            end_timer!(squeeze_time);
+
+            let (instance_combiners, circuit_combiner) = elems.split_at(*batch_size - 1);
+            ensure!(circuit_combiner.len() == num_c_combiner);
+            let mut combiners =
+                BatchCombiners { circuit_combiner: TargetField::one(), instance_combiners: vec![TargetField::one()] };
+            if num_c_combiner == 1 {
+                combiners.circuit_combiner = circuit_combiner[0];
+            }
+            combiners.instance_combiners.extend(instance_combiners);
+            batch_combiners.insert(*circuit_id, combiners);
+        }
+        Ok(FirstMessage { batch_combiners })
+    }
+
+    /// Squeezes the challenges of the second verifier message from the transcript.
+    pub fn derive_verifier_second_message<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
+        fs_rng: &mut R,
+    ) -> Result<SecondMessage<TargetField>> {
+        let elems = fs_rng.squeeze_nonnative_field_elements(3);
+        let (first, _) = elems.split_at(3);
+        let [alpha, eta_b, eta_c]: [_; 3] = first.try_into().map_err(anyhow::Error::msg)?;
+        Ok(SecondMessage { alpha, eta_b, eta_c })
+    }
+
+    /// Squeezes the challenge of the third verifier message from the transcript.
+    pub fn derive_verifier_third_message<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
+        fs_rng: &mut R,
+    ) -> ThirdMessage<TargetField> {
+        let elems = fs_rng.squeeze_nonnative_field_elements(1);
+        ThirdMessage { beta: elems[0] }
+    }
+
+    /// Squeezes the matrix combiners of the fourth verifier message for `num_circuits` circuits from the transcript.
+    /// The combiner of the first circuit's `A` matrix is always one.
+    pub fn derive_verifier_fourth_message<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
+        num_circuits: usize,
+        fs_rng: &mut R,
+    ) -> FourthMessage<TargetField> {
         let mut delta_a = Vec::with_capacity(num_circuits);
         let mut delta_b = Vec::with_capacity(num_circuits);
         let mut delta_c = Vec::with_capacity(num_circuits);
@@ -176,23 +224,15 @@ impl<TargetField: PrimeField, SM: SNARKMode> AHPForR1CS<TargetField, SM> {
             delta_b.push(elems[1]);
             delta_c.push(elems[2]);
         }
-        let message = FourthMessage { delta_a, delta_b, delta_c };
-
-        state.fourth_round_message = Some(message.clone());
-        Ok((message, state))
+        FourthMessage { delta_a, delta_b, delta_c }
     }
 
-    /// Output the next round state.
-    pub fn verifier_fifth_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
-        mut state: State<TargetField, SM>,
+    /// Squeezes the challenge `gamma` of the fifth verifier round from the transcript.
+    pub fn derive_verifier_fifth_challenge<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
         fs_rng: &mut R,
-    ) -> Result<State<TargetField, SM>> {
+    ) -> TargetField {
         let elems = fs_rng.squeeze_nonnative_field_elements(1);
-        let gamma = elems[0];
-        ensure!(!state.max_non_zero_domain.evaluate_vanishing_polynomial(gamma).is_zero());
-
-        state.gamma = Some(gamma);
-        Ok(state)
+        elems[0]
     }
 
     /// Output the query state and next round state.
@@ -16,7 +16,14 @@ use core::fmt::Debug;
 
 /// A trait to specify the SNARK mode.
 pub trait SNARKMode: 'static + Copy + Clone + Debug + PartialEq + Eq + Sync + Send {
+    /// Whether the proofs produced in this mode are hiding.
     const ZK: bool;
+
+    /// The number of queries each hiding oracle must stay zero-knowledge against.
+    /// Returns `None` for non-hiding modes, in which case the oracles carry no hiding terms.
+    fn zk_bound() -> Option<usize> {
+        Self::ZK.then_some(1)
+    }
 }
 
 /// This mode produces a hiding SNARK proof.
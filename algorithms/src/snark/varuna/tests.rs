@@ -155,6 +155,35 @@ mod varuna {
                     }
                 }
 
+                pub(crate) fn test_predicted_proof_size(num_constraints: usize, num_variables: usize) {
+                    let rng = &mut snarkvm_utilities::rand::TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $snark_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $snark_inst::universal_setup(max_degree).unwrap();
+                    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    let (circ, _) = TestCircuit::gen_rand(1, num_constraints, num_variables, rng);
+                    let (index_pk, index_vk) = $snark_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    let proof = $snark_inst::prove(universal_prover, &fs_parameters, &index_pk, &circ, rng).unwrap();
+
+                    let comms = &proof.commitments;
+                    let num_commitments = comms.witness_commitments.len()
+                        + usize::from(comms.mask_poly.is_some())
+                        + 3 * comms.g_a_commitments.len()
+                        + 4
+                        + proof.pc_proof.proof.0.len();
+                    let num_field_elements = 1
+                        + 3 * proof.evaluations.g_a_evals.len()
+                        + proof.third_msg.sums.iter().map(|sums| 3 * sums.len()).sum::<usize>()
+                        + 3 * proof.fourth_msg.sums.len()
+                        + proof.pc_proof.proof.0.iter().filter(|p| p.random_v.is_some()).count();
+
+                    let estimate = AHPForR1CS::<Fr, $snark_mode>::predicted_proof_size(&index_vk.circuit_info);
+                    assert_eq!(estimate.num_commitments, num_commitments);
+                    assert_eq!(estimate.num_field_elements, num_field_elements);
+                }
+
                 pub(crate) fn test_serde_json(num_constraints: usize, num_variables: usize) {
                     use std::str::FromStr;
 
@@ -213,6 +242,24 @@ mod varuna {
     impl_varuna_test!(SonicPCTest, VarunaSonicInst, VarunaHidingMode);
     impl_varuna_test!(SonicPCPoswTest, VarunaSonicPoSWInst, VarunaNonHidingMode);
 
+    #[test]
+    fn check_predicted_proof_size() {
+        SonicPCTest::test_predicted_proof_size(25, 25);
+        SonicPCPoswTest::test_predicted_proof_size(25, 25);
+
+        // The hiding mode additionally commits to the mask polynomial, and blinds the evaluation proof at `beta`.
+        let hiding = AHPForR1CS::<Fr, VarunaHidingMode>::predicted_batch_proof_size(&[2, 3]);
+        let non_hiding = AHPForR1CS::<Fr, VarunaNonHidingMode>::predicted_batch_proof_size(&[2, 3]);
+        assert_eq!(hiding.num_commitments, non_hiding.num_commitments + 1);
+        assert_eq!(hiding.num_field_elements, non_hiding.num_field_elements + 1);
+
+        // A single instance yields 12 commitments and 11 field elements when hiding, and 11 and 10 otherwise.
+        let hiding = AHPForR1CS::<Fr, VarunaHidingMode>::predicted_batch_proof_size(&[1]);
+        let non_hiding = AHPForR1CS::<Fr, VarunaNonHidingMode>::predicted_batch_proof_size(&[1]);
+        assert_eq!((hiding.num_commitments, hiding.num_field_elements), (12, 11));
+        assert_eq!((non_hiding.num_commitments, non_hiding.num_field_elements), (11, 10));
+    }
+
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
         let num_constraints = 100;
@@ -304,9 +351,12 @@ mod varuna_hiding {
     use crate::{
         crypto_hash::PoseidonSponge,
         snark::varuna::{
-            ahp::AHPForR1CS,
+            ahp::{indexer::Circuit, prover, verifier, witness_label, AHPError, AHPForR1CS, MaxDegreeParams},
             test_circuit::TestCircuit,
+            CircuitProvingKey,
             CircuitVerifyingKey,
+            SNARKMode,
+            UniversalSRS,
             VarunaHidingMode,
             VarunaSNARK,
         },
@@ -319,11 +369,61 @@ mod varuna_hiding {
         ToBytes,
     };
 
-    use std::str::FromStr;
+    use std::{collections::BTreeMap, ops::Deref, str::FromStr};
 
     type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
     type FS = PoseidonSponge<Fq, 2, 1>;
 
+    /// Returns the universal SRS used by the tests of the individual prover rounds.
+    fn universal_srs() -> UniversalSRS<Bls12_377> {
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        VarunaInst::universal_setup(max_degree).unwrap()
+    }
+
+    /// An indexed circuit, together with a batch of its instances.
+    struct Fixture<SM: SNARKMode> {
+        pk: CircuitProvingKey<Bls12_377, SM>,
+        vk: CircuitVerifyingKey<Bls12_377>,
+        circuits: Vec<TestCircuit<Fr>>,
+        public_inputs: Vec<Vec<Fr>>,
+    }
+
+    impl<SM: SNARKMode> Fixture<SM> {
+        /// Samples `batch_size` instances of a random circuit with the given dimensions, and indexes it.
+        fn new(
+            universal_srs: &UniversalSRS<Bls12_377>,
+            num_constraints: usize,
+            num_variables: usize,
+            batch_size: usize,
+            rng: &mut TestRng,
+        ) -> Self {
+            let (circuits, public_inputs): (Vec<_>, Vec<_>) =
+                (0..batch_size).map(|_| TestCircuit::gen_rand(2, num_constraints, num_variables, rng)).unzip();
+            let (pk, vk) = VarunaSNARK::<Bls12_377, FS, SM>::circuit_setup(universal_srs, &circuits[0]).unwrap();
+            Self { pk, vk, circuits, public_inputs }
+        }
+
+        /// Returns the batch in the form expected by the prover.
+        fn keys_to_constraints(&self) -> BTreeMap<&Circuit<Fr, SM>, &[TestCircuit<Fr>]> {
+            BTreeMap::from([(self.pk.circuit.deref(), self.circuits.as_slice())])
+        }
+
+        /// Initializes the prover for the batch, and runs its first round.
+        fn first_round(&self, rng: &mut TestRng) -> prover::State<'_, Fr, SM> {
+            let prover_state = AHPForR1CS::<_, SM>::init_prover(&self.keys_to_constraints(), rng).unwrap();
+            AHPForR1CS::<_, SM>::prover_first_round(prover_state, rng).unwrap()
+        }
+
+        /// Samples a first verifier message, with a random combiner for each instance of the batch.
+        fn first_message(&self, rng: &mut TestRng) -> verifier::FirstMessage<Fr> {
+            let combiners = verifier::BatchCombiners {
+                circuit_combiner: Fr::rand(rng),
+                instance_combiners: (0..self.circuits.len()).map(|_| Fr::rand(rng)).collect(),
+            };
+            verifier::FirstMessage { batch_combiners: BTreeMap::from([(self.pk.circuit.id, combiners)]) }
+        }
+    }
+
     fn test_circuit_n_times(num_constraints: usize, num_variables: usize, num_times: usize) {
         let rng = &mut TestRng::default();
 
@@ -459,6 +559,32 @@ mod varuna_hiding {
         test_circuit_n_times(num_constraints, num_variables, 1);
     }
 
+    #[test]
+    fn check_max_degree_for() {
+        let params = MaxDegreeParams { num_constraints: 100, num_variables: 25, num_non_zero: 300 };
+        assert_eq!(
+            AHPForR1CS::<Fr, VarunaHidingMode>::max_degree_for(params).unwrap(),
+            AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap()
+        );
+    }
+
+    #[test]
+    fn check_zk_bound() {
+        use crate::snark::varuna::{SNARKMode, VarunaNonHidingMode};
+
+        assert_eq!([VarunaHidingMode::ZK, VarunaNonHidingMode::ZK], [true, false]);
+        assert_eq!(VarunaHidingMode::zk_bound(), Some(1));
+        assert_eq!(VarunaNonHidingMode::zk_bound(), None);
+
+        assert_eq!(AHPForR1CS::<Fr, VarunaHidingMode>::zk_bound(), Some(1));
+        assert_eq!(AHPForR1CS::<Fr, VarunaHidingMode>::h_0_degree_bound(128), 256);
+        assert_eq!(AHPForR1CS::<Fr, VarunaHidingMode>::h_1_degree_bound(32), 64);
+
+        assert_eq!(AHPForR1CS::<Fr, VarunaNonHidingMode>::zk_bound(), None);
+        assert_eq!(AHPForR1CS::<Fr, VarunaNonHidingMode>::h_0_degree_bound(128), 254);
+        assert_eq!(AHPForR1CS::<Fr, VarunaNonHidingMode>::h_1_degree_bound(32), 62);
+    }
+
     #[test]
     fn check_indexing() {
         let rng = &mut TestRng::default();
@@ -489,6 +615,881 @@ mod varuna_hiding {
         assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &new_vk, public_inputs, &proof).unwrap());
     }
 
+    #[test]
+    fn check_assignments() {
+        use crate::r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+        use snarkvm_fields::{One, Zero};
+
+        // A circuit enforcing `a * b = c`, where `c` may be offset to produce an unsatisfied witness.
+        #[derive(Clone)]
+        struct MulCircuit {
+            a: Fr,
+            b: Fr,
+            offset: Fr,
+        }
+
+        impl ConstraintSynthesizer<Fr> for MulCircuit {
+            fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+                let a = cs.alloc(|| "a", || Ok(self.a))?;
+                let b = cs.alloc(|| "b", || Ok(self.b))?;
+                let c = cs.alloc_input(|| "c", || Ok(self.a * self.b + self.offset))?;
+                for i in 0..4 {
+                    cs.enforce(|| format!("constraint {i}"), |lc| lc + a, |lc| lc + b, |lc| lc + c);
+                }
+                Ok(())
+            }
+        }
+
+        let rng = &mut TestRng::default();
+        let universal_srs = universal_srs();
+
+        let satisfied = MulCircuit { a: Fr::rand(rng), b: Fr::rand(rng), offset: Fr::zero() };
+        let unsatisfied = MulCircuit { offset: Fr::one(), ..satisfied.clone() };
+        let (index_pk, _) = VarunaInst::circuit_setup(&universal_srs, &satisfied).unwrap();
+
+        // Check that the satisfied witness passes.
+        let keys_to_constraints = BTreeMap::from([(index_pk.circuit.deref(), std::slice::from_ref(&satisfied))]);
+        let prover_state = AHPForR1CS::<_, VarunaHidingMode>::init_prover(&keys_to_constraints, rng).unwrap();
+        assert!(AHPForR1CS::check_assignments(&prover_state).is_ok());
+
+        // Check that the unsatisfied witness reports the first constraint.
+        // With the `check_assignments` feature, the prover aborts during initialization instead.
+        let keys_to_constraints = BTreeMap::from([(index_pk.circuit.deref(), std::slice::from_ref(&unsatisfied))]);
+        let result = AHPForR1CS::<_, VarunaHidingMode>::init_prover(&keys_to_constraints, rng)
+            .and_then(|prover_state| AHPForR1CS::check_assignments(&prover_state));
+        match result {
+            Err(AHPError::UnsatisfiedConstraint(id, 0, 0)) => assert_eq!(id, index_pk.circuit.id),
+            result => panic!("Expected an unsatisfied constraint, found {result:?}"),
+        }
+
+        // Check that the prover aborts on the unsatisfied witness, when the check is enabled.
+        if cfg!(feature = "check_assignments") {
+            let universal_prover = &universal_srs.to_universal_prover().unwrap();
+            let fs_parameters = FS::sample_parameters();
+            assert!(VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &unsatisfied, rng).is_err());
+        }
+    }
+
+    #[test]
+    fn check_witness_artifacts() {
+        use crate::fft::EvaluationDomain;
+
+        let rng = &mut TestRng::default();
+        let batch_size = 3;
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, batch_size, rng);
+        let circuit = &fixture.pk.circuit;
+
+        // Run the first two rounds, collecting the witness polynomials.
+        let mut prover_state = fixture.first_round(rng);
+        assert!(prover_state.witness_artifacts().is_none());
+        prover_state.enable_witness_artifacts();
+        let verifier_first_msg = fixture.first_message(rng);
+        let (_, prover_state) =
+            AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng).unwrap();
+
+        // Ensure each instance satisfies `z_a * z_b = z_c` over the constraint domain.
+        let artifacts = prover_state.witness_artifacts().unwrap();
+        assert_eq!(artifacts.polynomials.len(), 3 * batch_size);
+        let constraint_domain = EvaluationDomain::<Fr>::new(circuit.index_info.num_constraints).unwrap();
+        for j in 0..batch_size {
+            let z_m = |m: &str| &artifacts.polynomials[&witness_label(circuit.id, m, j)];
+            for x in constraint_domain.elements() {
+                assert_eq!(z_m("z_a").evaluate(x) * z_m("z_b").evaluate(x), z_m("z_c").evaluate(x));
+            }
+        }
+
+        // Ensure the witness polynomials round-trip through their byte representation.
+        let bytes = artifacts.to_bytes_le().unwrap();
+        assert_eq!(*artifacts, FromBytes::read_le(&bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn check_compute_max_domains() {
+        use crate::snark::varuna::prover::State;
+
+        let rng = &mut TestRng::default();
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(200, 100, 600).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+
+        // Index circuits of different sizes, where neither is the largest in every dimension.
+        let fixture_1 = Fixture::<VarunaHidingMode>::new(&universal_srs, 150, 10, 1, rng);
+        let fixture_2 = Fixture::<VarunaHidingMode>::new(&universal_srs, 20, 80, 1, rng);
+        let circuits = [fixture_1.pk.circuit.deref(), fixture_2.pk.circuit.deref()];
+
+        // Ensure each maximum is taken over the whole batch, regardless of its order.
+        let domains = State::compute_max_domains(circuits).unwrap();
+        let [domains_1, domains_2] = circuits.map(|circuit| State::compute_max_domains([circuit]).unwrap());
+        assert_eq!(State::compute_max_domains([circuits[1], circuits[0]]).unwrap(), domains);
+        let max_size = |sizes: [usize; 2]| sizes.into_iter().max().unwrap();
+        assert_eq!(
+            domains.max_constraint_domain.size(),
+            max_size([domains_1, domains_2].map(|d| d.max_constraint_domain.size()))
+        );
+        assert_eq!(
+            domains.max_variable_domain.size(),
+            max_size([domains_1, domains_2].map(|d| d.max_variable_domain.size()))
+        );
+        assert_eq!(
+            domains.max_non_zero_domain.size(),
+            max_size([domains_1, domains_2].map(|d| d.max_non_zero_domain.size()))
+        );
+        assert_ne!(domains, domains_1);
+        assert_ne!(domains, domains_2);
+
+        // Ensure the prover state is initialized with the same domains.
+        let mut keys_to_constraints = fixture_1.keys_to_constraints();
+        keys_to_constraints.extend(fixture_2.keys_to_constraints());
+        let prover_state = AHPForR1CS::<_, VarunaHidingMode>::init_prover(&keys_to_constraints, rng).unwrap();
+        assert_eq!(prover_state.max_constraint_domain, domains.max_constraint_domain);
+        assert_eq!(prover_state.max_variable_domain, domains.max_variable_domain);
+        assert_eq!(prover_state.max_non_zero_domain, domains.max_non_zero_domain);
+
+        // Ensure an empty batch is rejected.
+        let result = State::<Fr, VarunaHidingMode>::compute_max_domains([]);
+        assert!(matches!(result, Err(AHPError::BatchSizeIsZero)));
+    }
+
+    #[test]
+    fn check_single_rowcheck_witness() {
+        use rand::RngCore;
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let batch_combiners = fixture.first_message(rng).batch_combiners;
+
+        // Run the first round twice from the same seed, so both paths start from identical states.
+        let seed = rng.next_u64();
+        let mut single_state = fixture.first_round(&mut TestRng::fixed(seed));
+        let mut batched_state = fixture.first_round(&mut TestRng::fixed(seed));
+
+        // Ensure the single-instance fast path matches the general path.
+        let single = AHPForR1CS::calculate_single_rowcheck_witness(&mut single_state, &batch_combiners).unwrap();
+        let batched = AHPForR1CS::calculate_batched_rowcheck_witness(&mut batched_state, &batch_combiners).unwrap();
+        assert_eq!(single, batched);
+    }
+
+    #[test]
+    fn check_rowcheck_accumulation_order() {
+        use rand::{seq::SliceRandom, RngCore};
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 4, rng);
+        let batch_combiners = fixture.first_message(rng).batch_combiners;
+
+        // Run the first round from the same seed, so every ordering starts from identical states.
+        let seed = rng.next_u64();
+        let first_round = || fixture.first_round(&mut TestRng::fixed(seed));
+        let expected = AHPForR1CS::calculate_batched_rowcheck_witness(&mut first_round(), &batch_combiners).unwrap();
+
+        // Ensure `h_0` is identical for shuffled job submission orders.
+        for _ in 0..4 {
+            let h_0 = AHPForR1CS::calculate_batched_rowcheck_witness_in_order(
+                &mut first_round(),
+                &batch_combiners,
+                |order| order.shuffle(rng),
+            )
+            .unwrap();
+            assert_eq!(expected.coeffs, h_0.coeffs);
+        }
+
+        // Ensure an order that is not a permutation of the jobs is rejected.
+        let result =
+            AHPForR1CS::calculate_batched_rowcheck_witness_in_order(&mut first_round(), &batch_combiners, |order| {
+                order[0] = order[1]
+            });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn check_combiner_mismatch() {
+        use crate::snark::varuna::ProverError;
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 2, rng);
+        let prover_state = fixture.first_round(rng);
+
+        // Ensure a missing instance combiner is reported, rather than silently dropping an instance.
+        let mut verifier_first_msg = fixture.first_message(rng);
+        for combiners in verifier_first_msg.batch_combiners.values_mut() {
+            combiners.instance_combiners.pop();
+        }
+        match AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng) {
+            Err(ProverError::CombinerMismatch(id)) => assert_eq!(id, fixture.pk.circuit.id),
+            result => panic!("Expected a combiner mismatch, found {:?}", result.err()),
+        }
+    }
+
+    #[test]
+    fn check_empty_batch() {
+        use crate::snark::varuna::ProverError;
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let mut prover_state = fixture.first_round(rng);
+
+        // Ensure a batch without any circuits is rejected before computing `h_0`.
+        prover_state.circuit_specific_states.clear();
+        let verifier_first_msg = verifier::FirstMessage { batch_combiners: BTreeMap::new() };
+        match AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng) {
+            Err(ProverError::EmptyBatch) => (),
+            result => panic!("Expected an empty batch, found {:?}", result.err()),
+        }
+    }
+
+    #[test]
+    fn check_out_of_order_rounds() {
+        use crate::snark::varuna::{prover::RoundStage, ProverError};
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let verifier_first_msg = fixture.first_message(rng);
+
+        // Ensure the second round cannot be run before the first round.
+        let prover_state = AHPForR1CS::<_, VarunaHidingMode>::init_prover(&fixture.keys_to_constraints(), rng).unwrap();
+        assert_eq!(prover_state.stage(), RoundStage::First);
+        match AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng) {
+            Err(ProverError::OutOfOrderRound { expected: RoundStage::First, got: RoundStage::Second }) => (),
+            result => panic!("Expected an out-of-order round, found {:?}", result.err()),
+        }
+
+        // Ensure the first round cannot be run twice.
+        let prover_state = fixture.first_round(rng);
+        assert_eq!(prover_state.stage(), RoundStage::Second);
+        match AHPForR1CS::<_, VarunaHidingMode>::prover_first_round(prover_state, rng) {
+            Err(AHPError::AnyhowError(error)) => assert!(matches!(
+                error.downcast_ref::<ProverError>(),
+                Some(ProverError::OutOfOrderRound { expected: RoundStage::Second, got: RoundStage::First })
+            )),
+            result => panic!("Expected an out-of-order round, found {:?}", result.err()),
+        }
+    }
+
+    #[test]
+    fn check_drop_round_artifacts() {
+        use crate::snark::varuna::prover::RoundStage;
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+
+        // Ensure the artifacts of a round cannot be dropped before the round has been run.
+        let mut prover_state =
+            AHPForR1CS::<_, VarunaHidingMode>::init_prover(&fixture.keys_to_constraints(), rng).unwrap();
+        assert!(prover_state.drop_round_artifacts(RoundStage::First).is_err());
+
+        // Ensure dropping the artifacts of the first round does not affect the second round.
+        let mut prover_state = AHPForR1CS::<_, VarunaHidingMode>::prover_first_round(prover_state, rng).unwrap();
+        assert!(prover_state.drop_round_artifacts(RoundStage::Second).is_err());
+        prover_state.drop_round_artifacts(RoundStage::First).unwrap();
+
+        let verifier_first_msg = fixture.first_message(rng);
+        let (_, mut prover_state) =
+            AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng).unwrap();
+        prover_state.drop_round_artifacts(RoundStage::Second).unwrap();
+        assert_eq!(prover_state.stage(), RoundStage::Third);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "after dropping the artifacts of the second round")]
+    fn check_dropped_round_artifacts_are_not_read() {
+        use crate::snark::varuna::prover::RoundStage;
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let verifier_first_msg = fixture.first_message(rng);
+        let (_, mut prover_state) =
+            AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, fixture.first_round(rng), rng)
+                .unwrap();
+        prover_state.drop_round_artifacts(RoundStage::Second).unwrap();
+
+        // Ensure reading the witnesses consumed by the second round again is caught.
+        let _ = AHPForR1CS::<_, VarunaHidingMode>::calculate_single_rowcheck_witness(
+            &mut prover_state,
+            &verifier_first_msg.batch_combiners,
+        );
+    }
+
+    #[test]
+    fn check_prover_state_hash() {
+        use crate::snark::varuna::prover::ProverConfig;
+        use rand::RngCore;
+
+        let rng = &mut TestRng::default();
+        let universal_srs = universal_srs();
+        let fixture_1 = Fixture::<VarunaHidingMode>::new(&universal_srs, 50, 25, 1, rng);
+        let fixture_2 = Fixture::<VarunaHidingMode>::new(&universal_srs, 40, 20, 1, rng);
+        let mut batch = fixture_1.keys_to_constraints();
+        batch.extend(fixture_2.keys_to_constraints());
+
+        // Ensure the hash does not depend on the order in which the batch was assembled.
+        // The witnesses are randomized in hiding mode, so both states are initialized from the same seed.
+        let seed = rng.next_u64();
+        let state_hash = |batch: &BTreeMap<_, _>, rng: &mut TestRng| {
+            AHPForR1CS::<_, VarunaHidingMode>::init_prover(batch, rng).unwrap().state_hash().unwrap()
+        };
+        let hash = state_hash(&batch, &mut TestRng::fixed(seed));
+        let mut reversed = fixture_2.keys_to_constraints();
+        reversed.extend(fixture_1.keys_to_constraints());
+        assert_eq!(state_hash(&reversed, &mut TestRng::fixed(seed)), hash);
+
+        // Ensure a different batch, or different randomness, results in a different hash.
+        assert_ne!(state_hash(&fixture_1.keys_to_constraints(), &mut TestRng::fixed(seed)), hash);
+        assert_ne!(state_hash(&batch, rng), hash);
+
+        // Ensure the hash is unaffected by the first round, and reflects the witnesses consumed by the second round.
+        let prover_state = AHPForR1CS::<_, VarunaHidingMode>::init_prover(&batch, &mut TestRng::fixed(seed)).unwrap();
+        let prover_state = AHPForR1CS::<_, VarunaHidingMode>::prover_first_round(prover_state, rng).unwrap();
+        assert_eq!(prover_state.state_hash().unwrap(), hash);
+        let mut verifier_first_msg = fixture_1.first_message(rng);
+        verifier_first_msg.batch_combiners.extend(fixture_2.first_message(rng).batch_combiners);
+        let (_, prover_state) =
+            AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng).unwrap();
+        assert_ne!(prover_state.state_hash().unwrap(), hash);
+
+        // Ensure the hash covers the outputs of each round if enabled, including the verifier messages.
+        let second_round_hash = |verifier_first_msg: &verifier::FirstMessage<Fr>| {
+            let rng = &mut TestRng::fixed(seed);
+            let mut prover_state = AHPForR1CS::<_, VarunaHidingMode>::init_prover(&batch, rng).unwrap();
+            prover_state.set_config(ProverConfig { hash_round_outputs: true, ..Default::default() });
+            let prover_state = AHPForR1CS::<_, VarunaHidingMode>::prover_first_round(prover_state, rng).unwrap();
+            let first_round_hash = prover_state.state_hash().unwrap();
+            let (_, prover_state) =
+                AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(verifier_first_msg, prover_state, rng).unwrap();
+            (first_round_hash, prover_state.state_hash().unwrap())
+        };
+        let (first_round_hash, second_round_hash_1) = second_round_hash(&verifier_first_msg);
+        assert_ne!(first_round_hash, hash);
+        assert_eq!(second_round_hash(&verifier_first_msg), (first_round_hash, second_round_hash_1));
+        let mut other_first_msg = verifier_first_msg.clone();
+        other_first_msg.batch_combiners.values_mut().for_each(|combiners| combiners.circuit_combiner = Fr::rand(rng));
+        let (other_first_round_hash, other_second_round_hash) = second_round_hash(&other_first_msg);
+        assert_eq!(other_first_round_hash, first_round_hash);
+        assert_ne!(other_second_round_hash, second_round_hash_1);
+    }
+
+    #[test]
+    fn check_degree_histogram() {
+        use crate::snark::varuna::prover::DegreeHistogram;
+
+        // Ensure the degrees are bucketed by powers of two.
+        let histogram = DegreeHistogram::default();
+        [0, 1, 2, 3, 4, 7].into_iter().for_each(|degree| histogram.record(degree));
+        assert_eq!(histogram.total(), 6);
+        assert_eq!(histogram.buckets(), vec![(0..=0, 1), (1..=1, 1), (2..=3, 2), (4..=7, 2)]);
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+
+        // Ensure the histogram is only collected if enabled.
+        let mut prover_state =
+            AHPForR1CS::<_, VarunaHidingMode>::init_prover(&fixture.keys_to_constraints(), rng).unwrap();
+        assert_eq!(prover_state.degree_histogram().is_some(), cfg!(feature = "degree_histogram"));
+        prover_state.enable_degree_histogram();
+
+        // Ensure the first round records the witness and mask polynomials.
+        let prover_state = AHPForR1CS::<_, VarunaHidingMode>::prover_first_round(prover_state, rng).unwrap();
+        assert_eq!(prover_state.degree_histogram().unwrap().total(), 2);
+
+        // Ensure the second round records `z_a`, `z_b`, `z_c`, and `h_0`.
+        let verifier_first_msg = fixture.first_message(rng);
+        let (oracles, prover_state) =
+            AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng).unwrap();
+        let histogram = prover_state.degree_histogram().unwrap();
+        assert_eq!(histogram.total(), 6);
+        let h_0_degree = oracles.h_0.polynomial().degree();
+        assert!(histogram.buckets().iter().any(|(range, _)| range.contains(&h_0_degree)));
+    }
+
+    #[test]
+    fn check_verify_interpolations() {
+        use crate::{
+            fft::{EvaluationDomain, Evaluations},
+            snark::varuna::{prover::ProverConfig, AHPError},
+        };
+        use rand::RngCore;
+        use snarkvm_fields::One;
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let keys_to_constraints = fixture.keys_to_constraints();
+        let verifier_first_msg = fixture.first_message(rng);
+
+        // Run both second rounds from the same seed, with the interpolations verified only if enabled.
+        let seed = rng.next_u64();
+        let [expected, candidate] = [false, true].map(|verify_interpolations| {
+            let rng = &mut TestRng::fixed(seed);
+            let mut prover_state = AHPForR1CS::<_, VarunaHidingMode>::init_prover(&keys_to_constraints, rng).unwrap();
+            assert!(!prover_state.config().verify_interpolations);
+            prover_state.set_config(ProverConfig { verify_interpolations, ..Default::default() });
+            assert_eq!(prover_state.config().verify_interpolations, verify_interpolations);
+            let prover_state = AHPForR1CS::<_, VarunaHidingMode>::prover_first_round(prover_state, rng).unwrap();
+            let (oracles, _) =
+                AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng).unwrap();
+            oracles.h_0
+        });
+
+        // Ensure verifying the interpolations does not change them.
+        assert_eq!(candidate.polynomial(), expected.polynomial());
+
+        // Ensure an interpolation is accepted, and a corrupted one is rejected.
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        let evaluations = (0..domain.size()).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let mut poly = Evaluations::from_vec_and_domain(evaluations.clone(), domain).interpolate();
+        let verify = AHPForR1CS::<Fr, VarunaHidingMode>::verify_interpolation;
+        assert!(verify("z_m", &poly, &evaluations, domain).is_ok());
+        poly.coeffs[0] += Fr::one();
+        let result = verify("z_m", &poly, &evaluations, domain);
+        assert!(matches!(result, Err(AHPError::InterpolationMismatch(label)) if label == "z_m"));
+    }
+
+    #[test]
+    fn check_calculate_z_m_batch() {
+        use crate::fft::EvaluationDomain;
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let circuit = &fixture.pk.circuit;
+
+        // Check the full constraint domain, and a subdomain of it.
+        let max_domain_size = circuit.ifft_precomputation.domain().size();
+        for domain_size in [max_domain_size, max_domain_size / 2] {
+            let constraint_domain = EvaluationDomain::<Fr>::new(domain_size).unwrap();
+            let labels = (0..4).map(|j| format!("z_m_{j}")).collect::<Vec<_>>();
+            let evaluations = (0..4)
+                .map(|_| (0..constraint_domain.size()).map(|_| Fr::rand(rng)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+
+            // Ensure the batched interpolation matches the per-instance interpolation.
+            let expected = labels
+                .iter()
+                .zip(evaluations.clone())
+                .map(|(label, evaluations)| {
+                    AHPForR1CS::<_, VarunaHidingMode>::calculate_z_m(
+                        label,
+                        evaluations,
+                        constraint_domain,
+                        circuit,
+                        Default::default(),
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let candidate = AHPForR1CS::<_, VarunaHidingMode>::calculate_z_m_batch(
+                labels.clone(),
+                evaluations.clone(),
+                constraint_domain,
+                circuit,
+                Default::default(),
+            )
+            .unwrap();
+            assert_eq!(candidate, expected);
+
+            // Ensure a mismatched number of labels is rejected.
+            assert!(AHPForR1CS::<_, VarunaHidingMode>::calculate_z_m_batch(
+                labels[1..].to_vec(),
+                evaluations,
+                constraint_domain,
+                circuit,
+                Default::default(),
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn check_calculate_z_m_pads_evaluations() {
+        use crate::{fft::EvaluationDomain, snark::varuna::VarunaNonHidingMode};
+        use snarkvm_fields::Zero;
+
+        // The number of constraints is not a power of two, so the witnesses are shorter than the constraint domain.
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaNonHidingMode>::new(&universal_srs(), 51, 25, 1, rng);
+
+        // Ensure the second round of a non-hiding proof pads the witnesses, rather than rejecting them.
+        let prover_state = fixture.first_round(rng);
+        let verifier_first_msg = fixture.first_message(rng);
+        AHPForR1CS::<_, VarunaNonHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng).unwrap();
+
+        // Ensure short evaluations are zero-padded, and excess evaluations are rejected.
+        let circuit = &fixture.pk.circuit;
+        let constraint_domain = EvaluationDomain::<Fr>::new(circuit.ifft_precomputation.domain().size()).unwrap();
+        let mut evaluations = (0..constraint_domain.size() - 3).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let z_m = |evaluations: Vec<Fr>| {
+            AHPForR1CS::<_, VarunaNonHidingMode>::calculate_z_m(
+                "z_m",
+                evaluations,
+                constraint_domain,
+                circuit,
+                Default::default(),
+            )
+        };
+        let candidate = z_m(evaluations.clone()).unwrap();
+        evaluations.resize(constraint_domain.size(), Fr::zero());
+        assert_eq!(candidate, z_m(evaluations.clone()).unwrap());
+        evaluations.push(Fr::rand(rng));
+        assert!(z_m(evaluations).is_err());
+    }
+
+    #[test]
+    fn check_prover_second_round_timed() {
+        use crate::snark::varuna::prover::RoundTiming;
+        use rand::RngCore;
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let verifier_first_msg = fixture.first_message(rng);
+
+        // Run the first round twice from the same seed, so both paths start from identical states.
+        let seed = rng.next_u64();
+        let untimed_state = fixture.first_round(&mut TestRng::fixed(seed));
+        let timed_state = fixture.first_round(&mut TestRng::fixed(seed));
+
+        // Ensure the timed round outputs the same oracles, and does not leave its timer in the state.
+        let (expected, _) =
+            AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, untimed_state, rng).unwrap();
+        let (candidate, prover_state, timing) =
+            AHPForR1CS::<_, VarunaHidingMode>::prover_second_round_timed(&verifier_first_msg, timed_state, rng)
+                .unwrap();
+        assert_eq!(candidate.h_0.polynomial(), expected.h_0.polynomial());
+        assert!(prover_state.round_timer.is_none());
+
+        // Ensure the phases of the round were timed.
+        assert_ne!(timing, RoundTiming::default());
+        assert!(timing.interpolation > Default::default());
+        assert!(timing.selector > Default::default());
+    }
+
+    #[test]
+    fn check_h_0_expected_degree() {
+        use crate::{fft::DensePolynomial, snark::varuna::ProverError};
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let prover_state = fixture.first_round(rng);
+        let max_constraint_domain = prover_state.max_constraint_domain();
+
+        let verifier_first_msg = fixture.first_message(rng);
+        let (oracles, prover_state) =
+            AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng).unwrap();
+
+        // Ensure a non-degenerate rowcheck has exactly the expected degree, and is not flagged.
+        let expected_degree = AHPForR1CS::<Fr, VarunaHidingMode>::h_0_expected_degree(max_constraint_domain.size());
+        assert_eq!(oracles.h_0.polynomial().degree(), expected_degree);
+        assert!(prover_state.warnings().is_empty());
+
+        // Ensure a collapsed rowcheck is flagged by the strict degree check.
+        let result =
+            AHPForR1CS::<Fr, VarunaHidingMode>::check_h_0_degree(&DensePolynomial::zero(), max_constraint_domain);
+        match cfg!(feature = "strict_degree_checks") {
+            true => assert!(matches!(result, Err(ProverError::DegreeTooLow(..)))),
+            false => assert!(result.is_ok()),
+        }
+    }
+
+    #[test]
+    fn check_second_oracles_from_h_0() {
+        use crate::{fft::DensePolynomial, snark::varuna::prover::SecondOracles};
+
+        let rng = &mut TestRng::default();
+        let info = AHPForR1CS::<Fr, VarunaHidingMode>::second_round_polynomial_info();
+
+        // Ensure an injected `h_0` is labeled and shaped like the prover's own oracle.
+        let h_0 = DensePolynomial::<Fr>::rand(10, rng);
+        let oracles = SecondOracles::from_h_0(h_0.clone());
+        assert!(oracles.matches_info(&info));
+        assert_eq!(oracles.h_0.label(), "h_0");
+        assert_eq!(oracles.h_0.polynomial().as_dense().unwrap(), &h_0);
+
+        // Ensure a malformed `h_0`, e.g. the zero polynomial, can be injected as well.
+        let oracles = SecondOracles::<Fr>::from_h_0(DensePolynomial::zero());
+        assert!(oracles.matches_info(&info));
+        assert!(oracles.h_0.polynomial().is_zero());
+
+        // Ensure an oracle without expected info is listed as a mismatch.
+        assert!(oracles.info_mismatches(&info).is_empty());
+        assert_eq!(oracles.info_mismatches(&BTreeMap::new()), vec!["'h_0' has no expected info".to_string()]);
+    }
+
+    #[test]
+    fn check_second_round_polynomial_info() {
+        use crate::snark::varuna::VarunaNonHidingMode;
+
+        // Ensure `h_0` declares no hiding bound in either mode, as it is built from the masked witnesses.
+        let hiding_info = AHPForR1CS::<Fr, VarunaHidingMode>::second_round_polynomial_info();
+        assert_eq!(hiding_info["h_0"].hiding_bound(), None);
+        let non_hiding_info = AHPForR1CS::<Fr, VarunaNonHidingMode>::second_round_polynomial_info();
+        assert_eq!(non_hiding_info["h_0"].hiding_bound(), None);
+        assert!(hiding_info["h_0"].degree_bound().is_none() && non_hiding_info["h_0"].degree_bound().is_none());
+
+        // Ensure the prover's `h_0` matches the declared info in the hiding mode.
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let prover_state = fixture.first_round(rng);
+        let verifier_first_msg = fixture.first_message(rng);
+        let (oracles, _) =
+            AHPForR1CS::<_, VarunaHidingMode>::prover_second_round(&verifier_first_msg, prover_state, rng).unwrap();
+        assert!(!oracles.h_0.is_hiding());
+        assert!(oracles.matches_info(&hiding_info));
+    }
+
+    #[test]
+    fn check_verifier_state_batch_size() {
+        use crate::{
+            fft::EvaluationDomain,
+            snark::varuna::verifier::{BatchCombiners, CircuitSpecificState},
+        };
+
+        let rng = &mut TestRng::default();
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        let new_state = |batch_size, combiners: &BatchCombiners<Fr>| {
+            CircuitSpecificState::new(domain, domain, domain, domain, domain, domain, batch_size, combiners)
+        };
+        let combiners =
+            BatchCombiners { circuit_combiner: Fr::rand(rng), instance_combiners: vec![Fr::rand(rng), Fr::rand(rng)] };
+
+        // Ensure a batch size that matches the instance combiners is accepted.
+        assert_eq!(new_state(2, &combiners).unwrap().batch_size, 2);
+
+        // Ensure a mismatched batch size is rejected.
+        let error = new_state(1, &combiners).unwrap_err();
+        assert_eq!(error.to_string(), "The batch size (1) does not match the number of instance combiners (2)");
+
+        // Ensure an empty batch is rejected.
+        let combiners = BatchCombiners { circuit_combiner: Fr::rand(rng), instance_combiners: vec![] };
+        assert!(new_state(0, &combiners).is_err());
+    }
+
+    #[test]
+    fn check_verifier_state_describe_domains() {
+        use crate::{
+            fft::EvaluationDomain,
+            snark::varuna::{
+                verifier::{BatchCombiners, CircuitSpecificState, State},
+                CircuitId,
+            },
+        };
+        use std::collections::BTreeMap;
+
+        let rng = &mut TestRng::default();
+        let small = EvaluationDomain::<Fr>::new(4).unwrap();
+        let large = EvaluationDomain::<Fr>::new(16).unwrap();
+        let combiners = BatchCombiners { circuit_combiner: Fr::rand(rng), instance_combiners: vec![Fr::rand(rng)] };
+        let circuit_state = CircuitSpecificState::new(small, small, large, small, large, small, 1, &combiners).unwrap();
+        let circuit_id = CircuitId([0u8; 32]);
+        let state = State::<Fr, VarunaHidingMode> {
+            circuit_specific_states: BTreeMap::from([(circuit_id, circuit_state)]),
+            max_constraint_domain: large,
+            max_variable_domain: small,
+            max_non_zero_domain: large,
+            first_round_message: None,
+            second_round_message: None,
+            third_round_message: None,
+            fourth_round_message: None,
+            gamma: None,
+            mode: core::marker::PhantomData,
+        };
+
+        let expected = format!(
+            "max_constraint_domain: 16\nmax_variable_domain: 4\nmax_non_zero_domain: 16\ncircuit {circuit_id}:\n  \
+             input_domain: 4\n  variable_domain: 4\n  constraint_domain: 16\n  non_zero_a_domain: 4\n  \
+             non_zero_b_domain: 16\n  non_zero_c_domain: 4\n"
+        );
+        assert_eq!(state.describe_domains(), expected);
+    }
+
+    #[test]
+    fn check_prover_verifier_domains_match() {
+        use crate::{fft::EvaluationDomain, snark::varuna::ahp::CircuitInfo};
+
+        let rng = &mut TestRng::default();
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs(), 50, 25, 1, rng);
+        let prover_state = AHPForR1CS::<_, VarunaHidingMode>::init_prover(&fixture.keys_to_constraints(), rng).unwrap();
+
+        let circuit_id = fixture.pk.circuit.id;
+        let batch_sizes = BTreeMap::from([(circuit_id, 1)]);
+        let fs_parameters = FS::sample_parameters();
+        let verifier_state = |info: &CircuitInfo, max_constraint_domain| {
+            let circuit_infos = BTreeMap::from([(circuit_id, info)]);
+            let (_, state) = AHPForR1CS::<Fr, VarunaHidingMode>::verifier_first_round(
+                &batch_sizes,
+                &circuit_infos,
+                max_constraint_domain,
+                prover_state.max_variable_domain,
+                prover_state.max_non_zero_domain,
+                &mut FS::new_with_parameters(&fs_parameters),
+            )
+            .unwrap();
+            state
+        };
+
+        // Ensure a verifier state built from the circuit info matches the prover state.
+        let info = fixture.pk.circuit.index_info;
+        let state = verifier_state(&info, prover_state.max_constraint_domain);
+        prover_state.check_domains_match(&state).unwrap();
+
+        // Ensure a mismatched maximum domain is reported.
+        let max_constraint_domain = prover_state.max_constraint_domain;
+        let smaller_domain = EvaluationDomain::new(max_constraint_domain.size() / 2).unwrap();
+        let state = verifier_state(&info, smaller_domain);
+        assert_eq!(
+            prover_state.check_domains_match(&state).unwrap_err().to_string(),
+            format!(
+                "max constraint domain size differs: prover {} vs verifier {}",
+                max_constraint_domain.size(),
+                smaller_domain.size()
+            )
+        );
+
+        // Ensure a mismatched circuit domain is reported.
+        let smaller_info = CircuitInfo { num_constraints: smaller_domain.size(), ..info };
+        let state = verifier_state(&smaller_info, max_constraint_domain);
+        assert_eq!(
+            prover_state.check_domains_match(&state).unwrap_err().to_string(),
+            format!(
+                "circuit {circuit_id} constraint domain size differs: prover {} vs verifier {}",
+                max_constraint_domain.size(),
+                smaller_domain.size()
+            )
+        );
+    }
+
+    #[test]
+    fn check_derive_verifier_messages() {
+        use crate::{
+            fft::EvaluationDomain,
+            snark::varuna::ahp::{CircuitId, CircuitInfo},
+        };
+        use std::collections::BTreeMap;
+
+        type Ahp = AHPForR1CS<Fr, VarunaHidingMode>;
+
+        let info = CircuitInfo {
+            num_public_inputs: 2,
+            num_public_and_private_variables: 32,
+            num_constraints: 32,
+            num_non_zero_a: 64,
+            num_non_zero_b: 64,
+            num_non_zero_c: 64,
+        };
+        let (id_1, id_2) = (CircuitId([0u8; 32]), CircuitId([1u8; 32]));
+        let batch_sizes = BTreeMap::from([(id_1, 2), (id_2, 1)]);
+        let circuit_infos = BTreeMap::from([(id_1, &info), (id_2, &info)]);
+        let domain = EvaluationDomain::<Fr>::new(64).unwrap();
+
+        // Ensure the derived messages match those of the verifier rounds, given the same transcript.
+        let fs_parameters = FS::sample_parameters();
+        let verifier_sponge = &mut FS::new_with_parameters(&fs_parameters);
+        let sponge = &mut FS::new_with_parameters(&fs_parameters);
+
+        let (first_msg, state) =
+            Ahp::verifier_first_round(&batch_sizes, &circuit_infos, domain, domain, domain, verifier_sponge).unwrap();
+        let derived = Ahp::derive_verifier_first_message(&batch_sizes, sponge).unwrap();
+        assert_eq!(derived.batch_combiners.len(), 2);
+        for (expected, candidate) in first_msg.batch_combiners.values().zip(derived.batch_combiners.values()) {
+            assert_eq!(expected.circuit_combiner, candidate.circuit_combiner);
+            assert_eq!(expected.instance_combiners, candidate.instance_combiners);
+        }
+
+        let (second_msg, state) = Ahp::verifier_second_round(state, verifier_sponge).unwrap();
+        let derived = Ahp::derive_verifier_second_message(sponge).unwrap();
+        assert_eq!(second_msg.alpha, derived.alpha);
+        assert_eq!(second_msg.eta_b, derived.eta_b);
+        assert_eq!(second_msg.eta_c, derived.eta_c);
+
+        let (third_msg, state) = Ahp::verifier_third_round(state, verifier_sponge).unwrap();
+        assert_eq!(third_msg.beta, Ahp::derive_verifier_third_message(sponge).beta);
+
+        let (fourth_msg, state) = Ahp::verifier_fourth_round(state, verifier_sponge).unwrap();
+        let derived = Ahp::derive_verifier_fourth_message(2, sponge);
+        assert_eq!(fourth_msg.delta_a, derived.delta_a);
+        assert_eq!(fourth_msg.delta_b, derived.delta_b);
+        assert_eq!(fourth_msg.delta_c, derived.delta_c);
+
+        let state = Ahp::verifier_fifth_round(state, verifier_sponge).unwrap();
+        assert_eq!(state.gamma, Some(Ahp::derive_verifier_fifth_challenge(sponge)));
+    }
+
+    #[test]
+    fn check_oracle_count_mismatch() {
+        use crate::SNARKError;
+
+        let rng = &mut TestRng::default();
+        let universal_srs = universal_srs();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let fixture = Fixture::<VarunaHidingMode>::new(&universal_srs, 50, 25, 1, rng);
+        let other = Fixture::<VarunaHidingMode>::new(&universal_srs, 25, 50, 1, rng);
+
+        let proof =
+            VarunaInst::prove(universal_prover, &fs_parameters, &fixture.pk, &fixture.circuits[0], rng).unwrap();
+        let keys_to_inputs = BTreeMap::from([(&fixture.vk, fixture.public_inputs.as_slice())]);
+        assert!(VarunaInst::verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &proof).unwrap());
+
+        // Ensure a proof for one circuit is rejected when verified against two circuits.
+        let mut two_keys_to_inputs = keys_to_inputs.clone();
+        two_keys_to_inputs.insert(&other.vk, other.public_inputs.as_slice());
+        assert!(VarunaInst::verify_batch(universal_verifier, &fs_parameters, &two_keys_to_inputs, &proof).is_err());
+
+        // Ensure a proof missing a first round oracle is rejected.
+        let mut missing_proof = proof.clone();
+        missing_proof.commitments.witness_commitments.pop();
+        let error = VarunaInst::verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &missing_proof);
+        assert!(matches!(
+            error.unwrap_err().downcast::<SNARKError>(),
+            Ok(SNARKError::OracleCountMismatch { round: "first", expected: 2, found: 1 })
+        ));
+
+        // Ensure a proof with an extra fourth round oracle is rejected.
+        let mut extra_proof = proof;
+        extra_proof.commitments.g_a_commitments.push(extra_proof.commitments.g_a_commitments[0]);
+        let error = VarunaInst::verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &extra_proof);
+        assert!(matches!(
+            error.unwrap_err().downcast::<SNARKError>(),
+            Ok(SNARKError::OracleCountMismatch { round: "fourth", expected: 3, found: 4 })
+        ));
+    }
+
+    #[test]
+    fn check_oracle_labels_are_unique() {
+        use crate::snark::varuna::ahp::{AHPError, CircuitId, CircuitInfo};
+        use std::collections::{BTreeMap, BTreeSet};
+
+        type Ahp = AHPForR1CS<Fr, VarunaHidingMode>;
+
+        // Sample two circuits, the first of which is proven for a batch of instances.
+        let info = CircuitInfo {
+            num_public_inputs: 2,
+            num_public_and_private_variables: 32,
+            num_constraints: 32,
+            num_non_zero_a: 64,
+            num_non_zero_b: 64,
+            num_non_zero_c: 64,
+        };
+        let circuits = BTreeMap::from([(CircuitId([0u8; 32]), (info, 3)), (CircuitId([1u8; 32]), (info, 1))]);
+
+        let round_infos = [
+            Ahp::index_polynomial_info(circuits.keys()),
+            Ahp::first_round_polynomial_info(circuits.iter().map(|(id, (_, batch_size))| (id, batch_size))),
+            Ahp::second_round_polynomial_info(),
+            Ahp::third_round_polynomial_info(32),
+            Ahp::fourth_round_polynomial_info(circuits.iter().map(|(id, (info, _))| (*id, info))),
+            Ahp::fifth_round_polynomial_info(),
+        ];
+
+        // Ensure no label is shared between rounds, as all oracles are combined into one map
+        // when constructing the linear combinations.
+        let num_labels = round_infos.iter().map(BTreeMap::len).sum::<usize>();
+        let labels = round_infos.iter().flat_map(BTreeMap::keys).collect::<BTreeSet<_>>();
+        assert_eq!(labels.len(), num_labels);
+        assert!(Ahp::check_unique_labels(labels.iter().map(|label| label.as_str())).is_ok());
+
+        // Ensure a label shared between rounds is rejected.
+        let duplicate = labels.iter().map(|label| label.as_str()).chain(["h_0"]);
+        assert!(matches!(Ahp::check_unique_labels(duplicate), Err(AHPError::DuplicateLabel(label)) if label == "h_0"));
+    }
+
     #[test]
     fn test_srs_downloads() {
         let rng = &mut TestRng::default();
@@ -531,13 +1532,13 @@ mod varuna_hiding {
 
 mod varuna_test_vectors {
     use crate::{
-        fft::EvaluationDomain,
+        fft::{DensePolynomial, EvaluationDomain},
         snark::varuna::{ahp::verifier, AHPForR1CS, TestCircuit, VarunaNonHidingMode, VarunaSNARK},
         traits::snark::SNARK,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_fields::One;
-    use std::{collections::BTreeMap, fs, ops::Deref, path::PathBuf, str::FromStr, sync::Arc};
+    use std::{collections::BTreeMap, fs, ops::Deref, path::PathBuf, str::FromStr};
 
     type FS = crate::crypto_hash::PoseidonSponge<Fq, 2, 1>;
     type MM = VarunaNonHidingMode;
@@ -587,6 +1588,109 @@ mod varuna_test_vectors {
         path
     }
 
+    // The verifier challenges of a test vector circuit, as listed in its `challenges.input` file.
+    struct Challenges {
+        alpha: Fr,
+        eta_b: Fr,
+        eta_c: Fr,
+        beta: Fr,
+        delta_a: Fr,
+        delta_b: Fr,
+        delta_c: Fr,
+    }
+
+    // The inputs of a test vector circuit, parsed and validated once from its resource files.
+    struct TestVectors {
+        // The witness values `a` and `b` used in the multiplicative constraints.
+        witnesses: (u128, u128),
+        // The verifier challenges.
+        challenges: Challenges,
+        // The rows of the `A`, `B`, and `C` matrices of the circuit.
+        instances: [Vec<Vec<u64>>; 3],
+    }
+
+    impl TestVectors {
+        // Loads the test vectors of the given circuit, panicking with the offending file if one is malformed.
+        fn load(circuit: &str) -> Self {
+            let read = |file: &str| {
+                let path = resources_path(false).join(circuit).join(file);
+                fs::read_to_string(&path).unwrap_or_else(|_| panic!("Could not read {path:?}"))
+            };
+
+            // Parse the witness values from the first line of `witness.input`.
+            let witness: Vec<u128> = read("witness.input")
+                .lines()
+                .next()
+                .and_then(|line| serde_json::from_str(line).ok())
+                .unwrap_or_else(|| panic!("Expected a list of witness values in witness.input of {circuit}"));
+            let [a, b]: [u128; 2] = witness.try_into().unwrap_or_else(|witness: Vec<_>| {
+                panic!("Expected 2 witness values in witness.input of {circuit}, found {}", witness.len())
+            });
+
+            // Parse the challenges from `challenges.input`, one per line.
+            let challenges = read("challenges.input")
+                .lines()
+                .map(|line| {
+                    Fr::from_str(line)
+                        .unwrap_or_else(|_| panic!("Invalid challenge '{line}' in challenges.input of {circuit}"))
+                })
+                .collect::<Vec<_>>();
+            // The test vectors do not depend on `eta_a` and `gamma`, so they are only validated.
+            let [alpha, _eta_a, eta_b, eta_c, beta, delta_a, delta_b, delta_c, _gamma]: [Fr; 9] =
+                challenges.try_into().unwrap_or_else(|challenges: Vec<_>| {
+                    panic!("Expected 9 challenges in challenges.input of {circuit}, found {}", challenges.len())
+                });
+
+            // Parse the matrices from `instance.input`, each of which is preceded by a line with its name.
+            let names = ["A", "B", "C"];
+            let mut instances: [Vec<Vec<u64>>; 3] = [Vec::new(), Vec::new(), Vec::new()];
+            let mut current = None;
+            for line in read("instance.input").lines().map(str::trim).filter(|line| !line.is_empty()) {
+                if let Some(index) = names.iter().position(|name| *name == line) {
+                    current = Some(index);
+                    continue;
+                }
+                let index = current
+                    .unwrap_or_else(|| panic!("Found a row before the first matrix in instance.input of {circuit}"));
+                let row = line
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|entry| !entry.is_empty())
+                    .map(|entry| match entry.parse() {
+                        Ok(value) => value,
+                        Err(_) => panic!("Invalid entry '{entry}' in instance.input of {circuit}"),
+                    })
+                    .collect();
+                instances[index].push(row);
+            }
+            for (name, matrix) in names.iter().zip(&instances) {
+                assert!(!matrix.is_empty(), "Matrix {name} is missing from instance.input of {circuit}");
+                assert!(
+                    matrix.iter().all(|row| row.len() == matrix[0].len()),
+                    "Matrix {name} in instance.input of {circuit} has rows of different lengths"
+                );
+            }
+
+            let challenges = Challenges { alpha, eta_b, eta_c, beta, delta_a, delta_b, delta_c };
+            Self { witnesses: (a, b), challenges, instances }
+        }
+
+        // Returns the witness values `a` and `b`.
+        fn witnesses(&self) -> (u128, u128) {
+            self.witnesses
+        }
+
+        // Returns the verifier challenges.
+        fn challenges(&self) -> &Challenges {
+            &self.challenges
+        }
+
+        // Returns the rows of the `A`, `B`, and `C` matrices.
+        fn instances(&self) -> &[Vec<Vec<u64>>; 3] {
+            &self.instances
+        }
+    }
+
     // Loads the given `test_folder/test_file` and asserts the given `candidate` matches the expected values.
     #[track_caller]
     fn assert_test_vector_equality(test_folder: &str, test_file: &str, candidate: &str, circuit: &str) {
@@ -597,6 +1701,33 @@ mod varuna_test_vectors {
         expect_test::expect_file![path].assert_eq(candidate);
     }
 
+    // Loads the given `test_folder/test_file` as polynomial coefficients, and asserts the given `candidate`
+    // coefficients represent the same polynomial, ignoring any trailing zero coefficients.
+    #[track_caller]
+    fn assert_polynomial_test_vector_equality(test_folder: &str, test_file: &str, candidate: &str, circuit: &str) {
+        // Parses the debug representation of a vector of coefficients.
+        let parse = |coeffs: &str| DensePolynomial {
+            coeffs: coeffs
+                .trim()
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .split(", ")
+                .filter(|coeff| !coeff.is_empty())
+                .map(|coeff| Fr::from_str(coeff).expect("Could not parse the coefficient"))
+                .collect(),
+        };
+
+        // Get the path to the test file.
+        let path = test_vector_path(test_folder, test_file, circuit, false);
+        let expected = parse(&fs::read_to_string(path).expect("Could not read the file"));
+
+        // Assert the candidate is equal to the expected polynomial.
+        assert!(
+            parse(candidate).eq_canonical(&expected),
+            "The polynomial '{test_file}' does not match the test vector"
+        );
+    }
+
     // Create a test vector from a trusted revision of Varuna.
     fn create_test_vector(folder: &str, file: &str, data: &str, circuit: &str) {
         // Get the path to the test file.
@@ -620,39 +1751,23 @@ mod varuna_test_vectors {
 
     // Test Varuna against test vectors for a specific circuit.
     fn test_circuit_with_test_vectors(create_test_vectors: bool, circuit: &str) {
-        // Initialize the parts of the witness used in the multiplicative constraints.
-        let witness_path = format!("src/snark/varuna/resources/{}/witness.input", circuit);
-        let instance_file = fs::read_to_string(witness_path).expect("Could not read the file");
-        let witness: Vec<u128> = serde_json::from_str(instance_file.lines().next().unwrap()).unwrap();
-        let (a, b) = (witness[0], witness[1]);
-
-        // Initialize challenges from file.
-        let challenges_path = format!("src/snark/varuna/resources/{}/challenges.input", circuit);
-        let challenges_file = fs::read_to_string(challenges_path).expect("Could not read the file");
-        let mut challenges = Vec::new();
-        for line in challenges_file.lines() {
-            challenges.push(line)
-        }
-        let (alpha, _eta_a, eta_b, eta_c, beta, delta_a, delta_b, delta_c, _gamma) = (
-            Fr::from_str(challenges[0]).unwrap(),
-            Fr::from_str(challenges[1]).unwrap(),
-            Fr::from_str(challenges[2]).unwrap(),
-            Fr::from_str(challenges[3]).unwrap(),
-            Fr::from_str(challenges[4]).unwrap(),
-            vec![Fr::from_str(challenges[5]).unwrap()],
-            vec![Fr::from_str(challenges[6]).unwrap()],
-            vec![Fr::from_str(challenges[7]).unwrap()],
-            Fr::from_str(challenges[8]).unwrap(),
-        );
-
-        let circuit_combiner = Fr::one();
-        let instance_combiners = vec![Fr::one()];
+        // Load the witness and the challenges of the circuit.
+        let vectors = TestVectors::load(circuit);
+        let (a, b) = vectors.witnesses();
+        let &Challenges { alpha, eta_b, eta_c, beta, delta_a, delta_b, delta_c } = vectors.challenges();
+        let (delta_a, delta_b, delta_c) = (vec![delta_a], vec![delta_b], vec![delta_c]);
 
         // Create sample circuit which corresponds to instance.input file.
         let mul_depth = 3;
         let num_constraints = 7;
         let num_variables = 7;
 
+        // Ensure the matrices of the instance.input file have the dimensions of the sample circuit.
+        for matrix in vectors.instances() {
+            assert_eq!(matrix.len(), num_constraints);
+            assert!(matrix.iter().all(|row| row.len() == num_variables));
+        }
+
         // Create a fixed seed rng that matches those the test vectors were generated with.
         let rng = &mut snarkvm_utilities::rand::TestRng::fixed(4730);
         let max_degree =
@@ -666,9 +1781,10 @@ mod varuna_test_vectors {
         keys_to_constraints.insert(index_pk.circuit.deref(), std::slice::from_ref(&circ));
 
         // Begin the Varuna protocol execution.
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&keys_to_constraints, rng).unwrap();
+        let mut prover_state = AHPForR1CS::<_, MM>::init_prover(&keys_to_constraints, rng).unwrap();
+        prover_state.enable_degree_histogram();
         let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, rng).unwrap();
-        let first_round_oracles = Arc::new(prover_state.first_round_oracles.as_ref().unwrap());
+        let first_round_oracles = prover_state.first_round_oracles().unwrap();
 
         // Get private witness polynomial coefficients.
         let (_, w_poly) = first_round_oracles.batches.iter().next().unwrap();
@@ -687,7 +1803,8 @@ mod varuna_test_vectors {
             create_test_vector("polynomials", "z_lde", &z_lde, circuit);
         }
 
-        let combiners = verifier::BatchCombiners::<Fr> { circuit_combiner, instance_combiners };
+        let combiners =
+            verifier::BatchCombiners::<Fr> { circuit_combiner: Fr::one(), instance_combiners: vec![Fr::one()] };
         let batch_combiners = BTreeMap::from_iter([(index_pk.circuit.id, combiners)]);
         let verifier_first_msg = verifier::FirstMessage::<Fr> { batch_combiners };
 
@@ -776,7 +1893,12 @@ mod varuna_test_vectors {
             create_test_vector("domain", "C", &format!("{:?}", variable_domain_elements), circuit);
         }
 
-        let fifth_oracles = AHPForR1CS::<_, MM>::prover_fifth_round(verifier_fourth_msg, prover_state, rng).unwrap();
+        let num_oracles = prover_state.degree_histogram().unwrap().total();
+        let (fifth_oracles, degree_histogram) =
+            AHPForR1CS::<_, MM>::prover_fifth_round(verifier_fourth_msg, prover_state, rng).unwrap();
+
+        // Ensure the degree histogram is returned, including `h_2`.
+        assert_eq!(degree_histogram.unwrap().total(), num_oracles + 1);
 
         // Get coefficients of final oracle polynomial from round 5.
         let h_2 = format!("{:?}", fifth_oracles.h_2.coeffs().map(|(_, coeff)| coeff).collect::<Vec<_>>());
@@ -787,13 +1909,13 @@ mod varuna_test_vectors {
         // Check the intermediate oracle polynomials against the test vectors.
         assert_test_vector_equality("polynomials", "w_lde", &w_lde, circuit);
         assert_test_vector_equality("polynomials", "z_lde", &z_lde, circuit);
-        assert_test_vector_equality("polynomials", "h_0", &h_0, circuit);
-        assert_test_vector_equality("polynomials", "h_1", &h_1, circuit);
-        assert_test_vector_equality("polynomials", "g_1", &g_1, circuit);
-        assert_test_vector_equality("polynomials", "h_2", &h_2, circuit);
-        assert_test_vector_equality("polynomials", "g_a", &g_a, circuit);
-        assert_test_vector_equality("polynomials", "g_b", &g_b, circuit);
-        assert_test_vector_equality("polynomials", "g_c", &g_c, circuit);
+        assert_polynomial_test_vector_equality("polynomials", "h_0", &h_0, circuit);
+        assert_polynomial_test_vector_equality("polynomials", "h_1", &h_1, circuit);
+        assert_polynomial_test_vector_equality("polynomials", "g_1", &g_1, circuit);
+        assert_polynomial_test_vector_equality("polynomials", "h_2", &h_2, circuit);
+        assert_polynomial_test_vector_equality("polynomials", "g_a", &g_a, circuit);
+        assert_polynomial_test_vector_equality("polynomials", "g_b", &g_b, circuit);
+        assert_polynomial_test_vector_equality("polynomials", "g_c", &g_c, circuit);
 
         // Check that the domains match the test vectors.
         assert_test_vector_equality("domain", "R", &format!("{:?}", constraint_domain_elements), circuit);
@@ -20,6 +20,7 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        LabeledPolynomial,
         QuerySet,
         Randomness,
         SonicKZG10,
@@ -196,7 +197,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
 
 impl<E: PairingEngine, FS, SM> SNARK for VarunaSNARK<E, FS, SM>
 where
-    E::Fr: PrimeField,
+    E::Fr: prover::WitnessField,
     E::Fq: PrimeField,
     FS: AlgebraicSponge<E::Fq, 2>,
     SM: SNARKMode,
@@ -388,7 +389,7 @@ where
 
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
         let (first_commitments, first_commitment_randomnesses) = {
-            let first_round_oracles = prover_state.first_round_oracles.as_ref().unwrap();
+            let first_round_oracles = prover_state.first_round_oracles().unwrap();
             SonicKZG10::<E, FS>::commit(
                 universal_prover,
                 &committer_key,
@@ -482,7 +483,7 @@ where
         // --------------------------------------------------------------------
 
         // We take out values from state before they are consumed.
-        let first_round_oracles = prover_state.first_round_oracles.take().unwrap();
+        let first_round_oracles = Arc::unwrap_or_clone(prover_state.first_round_oracles.take().unwrap());
         let index_a_polys =
             prover_state.circuit_specific_states.values_mut().flat_map(|s| s.a_polys.take().unwrap()).collect_vec();
         let index_b_polys =
@@ -490,7 +491,8 @@ where
 
         // --------------------------------------------------------------------
         // Fifth round
-        let fifth_oracles = AHPForR1CS::<_, SM>::prover_fifth_round(verifier_fourth_msg, prover_state, zk_rng)?;
+        // The proof has no room for the degree histogram, which is only returned by the AHP prover.
+        let (fifth_oracles, _) = AHPForR1CS::<_, SM>::prover_fifth_round(verifier_fourth_msg, prover_state, zk_rng)?;
 
         let fifth_round_comm_time = start_timer!(|| "Committing to fifth round polys");
         let (fifth_commitments, fifth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
@@ -510,11 +512,11 @@ where
         let polynomials: Vec<_> = index_a_polys
             .into_iter()
             .chain(index_b_polys)
-            .chain(first_round_oracles.into_iter())
-            .chain(second_oracles.into_iter())
-            .chain(third_oracles.into_iter())
-            .chain(fourth_oracles.into_iter())
-            .chain(fifth_oracles.into_iter())
+            .chain(first_round_oracles.into_polynomials())
+            .chain(second_oracles.into_polynomials())
+            .chain(third_oracles.into_polynomials())
+            .chain(fourth_oracles.into_polynomials())
+            .chain(fifth_oracles.into_polynomials())
             .collect();
         ensure!(
             polynomials.len()
@@ -525,6 +527,7 @@ where
             AHPForR1CS::<E::Fr, SM>::num_fourth_round_oracles(num_unique_circuits) +
             AHPForR1CS::<E::Fr, SM>::num_fifth_round_oracles()
         );
+        AHPForR1CS::<E::Fr, SM>::check_unique_labels(polynomials.iter().map(LabeledPolynomial::label))?;
 
         // Gather commitments in one vector.
         let witness_comm_len = if SM::ZK { first_commitments.len() - 1 } else { first_commitments.len() };
@@ -632,8 +635,39 @@ where
             bail!(SNARKError::EmptyBatch);
         }
 
+        // Ensure the proof contains the expected number of oracles in each round.
+        // The second, third, and fifth round oracles are fixed fields of the proof,
+        // so only the first and fourth rounds, which grow with the batch, are counted.
+        let comms = &proof.commitments;
+        let total_instances = proof
+            .batch_sizes()
+            .iter()
+            .try_fold(0usize, |acc, &size| acc.checked_add(size))
+            .ok_or(SNARKError::BatchSizeMismatch)?;
+        let oracle_counts = [
+            (
+                "first",
+                AHPForR1CS::<E::Fr, SM>::num_first_round_oracles(total_instances),
+                comms.witness_commitments.len() + usize::from(comms.mask_poly.is_some()),
+            ),
+            (
+                "fourth",
+                AHPForR1CS::<E::Fr, SM>::num_fourth_round_oracles(keys_to_inputs.len()),
+                comms.g_a_commitments.len() + comms.g_b_commitments.len() + comms.g_c_commitments.len(),
+            ),
+        ];
+        for (round, expected, found) in oracle_counts {
+            if found != expected {
+                bail!(SNARKError::OracleCountMismatch { round, expected, found });
+            }
+        }
+
         proof.check_batch_sizes()?;
         let batch_sizes_vec = proof.batch_sizes();
+        // Ensure the proof is for as many circuits as there are verifying keys.
+        if batch_sizes_vec.len() != keys_to_inputs.len() {
+            bail!(SNARKError::BatchSizeMismatch);
+        }
         let mut batch_sizes = BTreeMap::new();
         for (i, (vk, public_inputs_i)) in keys_to_inputs.iter().enumerate() {
             batch_sizes.insert(vk.id, batch_sizes_vec[i]);
@@ -712,7 +746,6 @@ where
             EvaluationDomain::<E::Fr>::new(max_num_variables).ok_or(SynthesisError::PolyTooLarge)?;
         let max_non_zero_domain = max_non_zero_domain.ok_or(SynthesisError::PolyTooLarge)?;
 
-        let comms = &proof.commitments;
         let proof_has_correct_zk_mode = if SM::ZK {
             proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
         } else {
@@ -855,6 +888,7 @@ where
             .chain(fourth_commitments)
             .chain(fifth_commitments)
             .collect();
+        AHPForR1CS::<E::Fr, SM>::check_unique_labels(commitments.iter().map(LabeledCommitment::label))?;
 
         let query_set_time = start_timer!(|| "Constructing query set");
         let (query_set, verifier_state) = AHPForR1CS::<_, SM>::verifier_query_set(verifier_state);
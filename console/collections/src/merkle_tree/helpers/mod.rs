@@ -15,5 +15,8 @@
 mod leaf_hash;
 pub use leaf_hash::*;
 
+mod padding;
+pub use padding::*;
+
 mod path_hash;
 pub use path_hash::*;
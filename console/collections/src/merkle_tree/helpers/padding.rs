@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The policy for extending a non-power-of-two set of leaves up to the next power of two.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PaddingPolicy<Leaf> {
+    /// Pads with the canonical empty hash of the path hasher.
+    /// This is the policy of `MerkleTree::new`, and thus the one used by the ledger.
+    Empty,
+    /// Pads with the hash of the last leaf.
+    RepeatLast,
+    /// Pads with the hash of the given leaf.
+    ExplicitPad(Leaf),
+}
+
+impl<Leaf> Default for PaddingPolicy<Leaf> {
+    /// Returns the `Empty` padding policy.
+    fn default() -> Self {
+        Self::Empty
+    }
+}
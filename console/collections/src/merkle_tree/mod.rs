@@ -25,7 +25,7 @@ use snarkvm_console_types::prelude::*;
 
 use aleo_std::prelude::*;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -44,14 +44,31 @@ pub struct MerkleTree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHas
     empty_hash: Field<E>,
     /// The number of hashed leaves in the tree.
     number_of_leaves: usize,
+    /// Whether the leaves were padded with a hash other than the empty hash.
+    padded_explicitly: bool,
+    /// An optional map from each leaf hash to the indices of its occurrences, enabled by `with_leaf_index`.
+    leaf_index: Option<HashMap<Field<E>, BTreeSet<usize>>>,
 }
 
 impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
     MerkleTree<E, LH, PH, DEPTH>
 {
     #[inline]
-    /// Initializes a new Merkle tree with the given leaves.
+    /// Initializes a new Merkle tree with the given leaves, padded with the empty hash.
     pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
+        Self::new_with_padding(leaf_hasher, path_hasher, leaves, &PaddingPolicy::Empty)
+    }
+
+    #[inline]
+    /// Initializes a new Merkle tree with the given leaves, padded according to the given policy.
+    ///
+    /// Note: A tree padded with anything other than the empty hash may not be appended to, updated, or truncated.
+    pub fn new_with_padding(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaves: &[LH::Leaf],
+        padding: &PaddingPolicy<LH::Leaf>,
+    ) -> Result<Self> {
         let timer = timer!("MerkleTree::new");
 
         // Ensure the Merkle tree depth is greater than 0.
@@ -90,6 +107,19 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         tree[num_nodes..num_nodes + leaves.len()].copy_from_slice(&leaf_hasher.hash_leaves(leaves)?);
         lap!(timer, "Hashed {} leaves", leaves.len());
 
+        // Compute the padding leaf hash, if the policy requires one and there is at least one leaf.
+        let padding_hash = match padding {
+            _ if leaves.is_empty() => None,
+            PaddingPolicy::Empty => None,
+            PaddingPolicy::RepeatLast => Some(tree[num_nodes + leaves.len() - 1]),
+            PaddingPolicy::ExplicitPad(leaf) => Some(leaf_hasher.hash_leaf(leaf)?),
+        };
+        // Pad the leaves up to the maximum number of leaves with the padding leaf hash.
+        if let Some(padding_hash) = padding_hash {
+            tree.resize(num_nodes + max_leaves, padding_hash);
+            tree[num_nodes + leaves.len()..].fill(padding_hash);
+        }
+
         // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
         let mut start_index = num_nodes;
         // Compute the start index of the current level.
@@ -134,6 +164,8 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             tree,
             empty_hash,
             number_of_leaves: leaves.len(),
+            padded_explicitly: padding_hash.is_some(),
+            leaf_index: None,
         })
     }
 
@@ -142,6 +174,9 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_append");
 
+        // Ensure the tree is padded with the empty hash.
+        self.ensure_empty_padding()?;
+
         // Compute the maximum number of leaves.
         let max_leaves = match (self.number_of_leaves + new_leaves.len()).checked_next_power_of_two() {
             Some(num_leaves) => num_leaves,
@@ -210,14 +245,17 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
 
         finish!(timer);
 
-        Ok(Self {
+        Self {
             leaf_hasher: self.leaf_hasher.clone(),
             path_hasher: self.path_hasher.clone(),
             root: root_hash,
             tree,
             empty_hash: self.empty_hash,
             number_of_leaves: self.number_of_leaves + new_leaves.len(),
-        })
+            padded_explicitly: false,
+            leaf_index: None,
+        }
+        .reindexed(self.leaf_index.is_some())
     }
 
     #[inline]
@@ -248,11 +286,77 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         Ok(())
     }
 
+    #[inline]
+    /// Updates the Merkle tree in place at the location of the given leaf index with the new leaf,
+    /// recomputing only the path from the leaf to the root, and returns the new root.
+    pub fn update_leaf(&mut self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<PH::Hash> {
+        let timer = timer!("MerkleTree::update_leaf");
+
+        // Compute the new path hashes and root, so the tree is not altered in case of failure.
+        let (start, path_hashes, root_hash) = self.compute_updated_path(leaf_index, new_leaf)?;
+
+        // Update the leaf index, if it is enabled.
+        Self::reindex_leaf(&mut self.leaf_index, leaf_index, &self.tree[start + leaf_index], &path_hashes[0]);
+        // Update the path from the leaf to the root with the new path hashes.
+        let mut index = Some(start + leaf_index);
+        for path_hash in path_hashes {
+            self.tree[index.unwrap()] = path_hash;
+            index = parent(index.unwrap());
+        }
+        // Update the root hash.
+        self.root = root_hash;
+
+        finish!(timer);
+        Ok(root_hash)
+    }
+
     #[inline]
     /// Returns a new Merkle tree with updates at the location of the given leaf index with the new leaf.
     pub fn prepare_update(&self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_update");
 
+        // Compute the new path hashes and root.
+        let (start, path_hashes, root_hash) = self.compute_updated_path(leaf_index, new_leaf)?;
+        lap!(timer, "Computed the updated path");
+
+        // Initialize the Merkle tree.
+        let mut tree = Vec::with_capacity(self.tree.len());
+        // Extend the new Merkle tree with the existing leaf hashes.
+        tree.extend(&self.tree);
+
+        // Update the leaf index, if it is enabled.
+        let mut leaf_index_map = self.leaf_index.clone();
+        Self::reindex_leaf(&mut leaf_index_map, leaf_index, &self.tree[start + leaf_index], &path_hashes[0]);
+
+        // Update the rest of the tree with the new path hashes.
+        let mut index = Some(start + leaf_index);
+        for path_hash in path_hashes {
+            tree[index.unwrap()] = path_hash;
+            index = parent(index.unwrap());
+        }
+
+        finish!(timer);
+
+        Ok(Self {
+            leaf_hasher: self.leaf_hasher.clone(),
+            path_hasher: self.path_hasher.clone(),
+            root: root_hash,
+            tree,
+            empty_hash: self.empty_hash,
+            number_of_leaves: self.number_of_leaves,
+            padded_explicitly: false,
+            leaf_index: leaf_index_map,
+        })
+    }
+
+    /// Returns the start index of the leaf hashes, the new hashes for the path from the given leaf index
+    /// to the root of the tree, and the new root, for an update of the leaf to the new leaf.
+    fn compute_updated_path(&self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<(usize, Vec<PH::Hash>, PH::Hash)> {
+        let timer = timer!("MerkleTree::compute_updated_path");
+
+        // Ensure the tree is padded with the empty hash.
+        self.ensure_empty_padding()?;
+
         // Check that the leaf index is within the bounds of the Merkle tree.
         ensure!(
             leaf_index < self.number_of_leaves,
@@ -301,28 +405,8 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         }
         lap!(timer, "Hashed {} padding levels", padding_depth);
 
-        // Initialize the Merkle tree.
-        let mut tree = Vec::with_capacity(self.tree.len());
-        // Extend the new Merkle tree with the existing leaf hashes.
-        tree.extend(&self.tree);
-
-        // Update the rest of the tree with the new path hashes.
-        let mut index = Some(start + leaf_index);
-        for path_hash in path_hashes {
-            tree[index.unwrap()] = path_hash;
-            index = parent(index.unwrap());
-        }
-
         finish!(timer);
-
-        Ok(Self {
-            leaf_hasher: self.leaf_hasher.clone(),
-            path_hasher: self.path_hasher.clone(),
-            root: root_hash,
-            tree,
-            empty_hash: self.empty_hash,
-            number_of_leaves: self.number_of_leaves,
-        })
+        Ok((start, path_hashes, root_hash))
     }
 
     #[inline]
@@ -330,6 +414,9 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn update_many(&mut self, updates: &BTreeMap<usize, LH::Leaf>) -> Result<()> {
         let timer = timer!("MerkleTree::update_many");
 
+        // Ensure the tree is padded with the empty hash.
+        self.ensure_empty_padding()?;
+
         // Check that there are updates to perform.
         ensure!(!updates.is_empty(), "There must be at least one leaf to update in the Merkle tree");
 
@@ -444,6 +531,10 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Update the root hash.
         self.root = root_hash;
 
+        // Update the leaf index, if it is enabled.
+        for (index, hash) in &updated_hashes[0] {
+            Self::reindex_leaf(&mut self.leaf_index, index - start, &self.tree[*index], hash);
+        }
         // Update the rest of the tree with the updated hashes.
         for (index, hash) in updated_hashes.into_iter().flatten() {
             self.tree[index] = hash;
@@ -458,6 +549,9 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn prepare_remove_last_n(&self, n: usize) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_remove_last_n");
 
+        // Ensure the tree is padded with the empty hash.
+        self.ensure_empty_padding()?;
+
         ensure!(n > 0, "Cannot remove zero leaves from the Merkle tree");
 
         // Determine the updated number of leaves, after removing the last 'n' leaves.
@@ -532,14 +626,17 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
 
         finish!(timer);
 
-        Ok(Self {
+        Self {
             leaf_hasher: self.leaf_hasher.clone(),
             path_hasher: self.path_hasher.clone(),
             root: root_hash,
             tree,
             empty_hash: self.empty_hash,
             number_of_leaves: updated_number_of_leaves,
-        })
+            padded_explicitly: false,
+            leaf_index: None,
+        }
+        .reindexed(self.leaf_index.is_some())
     }
 
     #[inline]
@@ -604,6 +701,25 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         MerklePath::try_from((U64::new(leaf_index as u64), path))
     }
 
+    /// Returns the Merkle tree with a map from each leaf hash to its index, for looking up leaves with `index_of`.
+    /// The map costs memory proportional to the number of leaves. Updating a leaf adjusts the map in place,
+    /// while appending and removing leaves rebuild it along with the tree.
+    pub fn with_leaf_index(self) -> Result<Self> {
+        self.reindexed(true)
+    }
+
+    /// Returns the index of the first leaf in the Merkle tree equal to the given leaf, if there is one.
+    /// If the leaf index is not enabled with `with_leaf_index`, this scans the leaf hashes.
+    pub fn index_of(&self, leaf: &LH::Leaf) -> Result<Option<usize>> {
+        // Compute the leaf hash.
+        let leaf_hash = self.leaf_hasher.hash_leaf(leaf)?;
+        // Look up the leaf hash.
+        match &self.leaf_index {
+            Some(leaf_index) => Ok(leaf_index.get(&leaf_hash).and_then(|indices| indices.first().copied())),
+            None => Ok(self.leaf_hashes()?.iter().position(|hash| *hash == leaf_hash)),
+        }
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
         path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
@@ -642,6 +758,51 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         self.number_of_leaves
     }
 
+    /// Returns the Merkle tree with its leaf index rebuilt if `enabled`, and without a leaf index otherwise.
+    fn reindexed(mut self, enabled: bool) -> Result<Self> {
+        self.leaf_index = enabled.then(HashMap::new);
+        self.reindex()?;
+        Ok(self)
+    }
+
+    /// Rebuilds the leaf index from the leaf hashes, if it is enabled.
+    fn reindex(&mut self) -> Result<()> {
+        if self.leaf_index.is_some() {
+            let mut leaf_index = HashMap::<_, BTreeSet<_>>::with_capacity(self.number_of_leaves);
+            for (index, hash) in self.leaf_hashes()?.iter().enumerate() {
+                leaf_index.entry(*hash).or_default().insert(index);
+            }
+            self.leaf_index = Some(leaf_index);
+        }
+        Ok(())
+    }
+
+    /// Moves the given leaf from its old hash to its new hash in the leaf index, if it is enabled.
+    fn reindex_leaf(
+        leaf_index: &mut Option<HashMap<Field<E>, BTreeSet<usize>>>,
+        index: usize,
+        old_hash: &Field<E>,
+        new_hash: &Field<E>,
+    ) {
+        if let Some(leaf_index) = leaf_index {
+            // Remove the leaf from its old hash, dropping the hash once it has no occurrences left.
+            if let Some(indices) = leaf_index.get_mut(old_hash) {
+                indices.remove(&index);
+                if indices.is_empty() {
+                    leaf_index.remove(old_hash);
+                }
+            }
+            // Add the leaf to its new hash.
+            leaf_index.entry(*new_hash).or_default().insert(index);
+        }
+    }
+
+    /// Ensures the leaves are padded with the empty hash, which appending, updating, and removing leaves assume.
+    fn ensure_empty_padding(&self) -> Result<()> {
+        ensure!(!self.padded_explicitly, "Cannot modify a Merkle tree that is not padded with the empty hash");
+        Ok(())
+    }
+
     /// Compute and store the hashes for each level, iterating from the penultimate level to the root level.
     ///
     /// ```ignore
@@ -59,25 +59,36 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
         root: &PH::Hash,
         leaf: &LH::Leaf,
     ) -> bool {
-        // Ensure the leaf index is within the tree depth.
-        if (*self.leaf_index as u128) >= (1u128 << DEPTH) {
-            eprintln!("Found an out of bounds Merkle leaf index");
-            return false;
+        match self.verify_with_hashes(leaf_hasher, path_hasher, root, leaf) {
+            Ok((is_valid, _)) => is_valid,
+            Err(error) => {
+                eprintln!("{error}");
+                false
+            }
         }
+    }
+
+    /// Returns `true` if the Merkle path is valid for the given root and leaf, along with the hashes
+    /// computed while walking the path: the leaf hash, followed by the node hash on each level up to the root.
+    /// Returns an error if the path is malformed, or if hashing fails.
+    pub fn verify_with_hashes<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+    ) -> Result<(bool, Vec<PH::Hash>)> {
+        // Ensure the leaf index is within the tree depth.
+        ensure!((*self.leaf_index as u128) < (1u128 << DEPTH), "Found an out of bounds Merkle leaf index");
         // Ensure the path length matches the expected depth.
-        else if self.siblings.len() != DEPTH as usize {
-            eprintln!("Found an incorrect Merkle path length");
-            return false;
-        }
+        ensure!(self.siblings.len() == DEPTH as usize, "Found an incorrect Merkle path length");
 
-        // Initialize a tracker for the current hash, by computing the leaf hash to start.
-        let mut current_hash = match leaf_hasher.hash_leaf(leaf) {
-            Ok(candidate_leaf_hash) => candidate_leaf_hash,
-            Err(error) => {
-                eprintln!("Failed to hash the Merkle leaf during verification: {error}");
-                return false;
-            }
-        };
+        // Initialize the hashes, by computing the leaf hash to start.
+        let mut hashes = Vec::with_capacity(DEPTH as usize + 1);
+        let mut current_hash = leaf_hasher
+            .hash_leaf(leaf)
+            .map_err(|error| anyhow!("Failed to hash the Merkle leaf during verification: {error}"))?;
+        hashes.push(current_hash);
 
         // Compute the ordering of the current hash and sibling hash on each level.
         // If the indicator bit is `true`, then the ordering is (current_hash, sibling_hash).
@@ -92,17 +103,14 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
                 false => (*sibling_hash, current_hash),
             };
             // Update the current hash for the next level.
-            match path_hasher.hash_children(&left, &right) {
-                Ok(hash) => current_hash = hash,
-                Err(error) => {
-                    eprintln!("Failed to hash the Merkle path during verification: {error}");
-                    return false;
-                }
-            }
+            current_hash = path_hasher
+                .hash_children(&left, &right)
+                .map_err(|error| anyhow!("Failed to hash the Merkle path during verification: {error}"))?;
+            hashes.push(current_hash);
         }
 
         // Ensure the final hash matches the given root.
-        current_hash == *root
+        Ok((current_hash == *root, hashes))
     }
 }
 
@@ -131,6 +139,86 @@ impl<E: Environment, const DEPTH: u8> ToBytes for MerklePath<E, DEPTH> {
     }
 }
 
+impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+    /// Returns the size of the unframed byte representation: u64 + (Field::SIZE_IN_BYTES * DEPTH).
+    fn unframed_size_in_bytes() -> usize {
+        8 + DEPTH as usize * ((Field::<E>::size_in_bits() + 7) / 8)
+    }
+
+    /// Reads in a Merkle path from a buffer, prefixed by its length in bytes.
+    pub fn read_le_framed<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the length of the Merkle path.
+        let length = u32::read_le(&mut reader)? as usize;
+        // Ensure the length matches the expected size, before consuming the Merkle path.
+        if length != Self::unframed_size_in_bytes() {
+            return Err(error(format!(
+                "Found a Merkle path of {length} bytes, expected {}",
+                Self::unframed_size_in_bytes()
+            )));
+        }
+        // Read the Merkle path bytes.
+        let mut bytes = vec![0u8; length];
+        reader.read_exact(&mut bytes)?;
+        // Return the Merkle path.
+        Self::read_le(&bytes[..])
+    }
+
+    /// Writes the Merkle path to a buffer, prefixed by its length in bytes.
+    pub fn write_le_framed<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the length of the Merkle path.
+        u32::try_from(Self::unframed_size_in_bytes()).map_err(error)?.write_le(&mut writer)?;
+        // Write the Merkle path.
+        self.write_le(&mut writer)
+    }
+
+    /// Skips past a length-prefixed Merkle path in a buffer, without parsing it.
+    pub fn skip_framed<R: Read>(mut reader: R) -> IoResult<()> {
+        // Read the length of the Merkle path.
+        let length = u32::read_le(&mut reader)? as u64;
+        // Consume the Merkle path bytes, ensuring the buffer is not truncated.
+        match std::io::copy(&mut reader.by_ref().take(length), &mut std::io::sink())? == length {
+            true => Ok(()),
+            false => Err(error("Found a truncated Merkle path")),
+        }
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
+    /// Returns the Merkle path as readable JSON, with the index bits and the sibling hashes in hex.
+    pub fn to_json(&self) -> Result<serde_json::Value> {
+        // Compute the index bit on each level, from the leaf to the root.
+        let index_bits = (0..DEPTH).map(|i| (*self.leaf_index >> i) & 1 == 1).collect::<Vec<_>>();
+        // Encode the sibling hashes in hex.
+        let siblings =
+            self.siblings.iter().map(|sibling| sibling.to_bytes_le().map(hex::encode)).collect::<Result<Vec<_>>>()?;
+        Ok(serde_json::json!({
+            "leaf_index": *self.leaf_index,
+            "index_bits": index_bits,
+            "siblings": siblings,
+        }))
+    }
+
+    /// Returns the Merkle path from the readable JSON produced by `MerklePath::to_json`.
+    pub fn from_json(json: &serde_json::Value) -> Result<Self> {
+        // Retrieve the leaf index.
+        let leaf_index = json["leaf_index"].as_u64().ok_or_else(|| anyhow!("Missing the Merkle path leaf index"))?;
+        // Ensure the index bits match the leaf index.
+        let index_bits = Vec::<bool>::deserialize(&json["index_bits"])?;
+        let is_bit_set = |i: u8| leaf_index.checked_shr(u32::from(i)).unwrap_or(0) & 1 == 1;
+        ensure!(
+            index_bits.len() == DEPTH as usize && (0..DEPTH).zip(&index_bits).all(|(i, bit)| is_bit_set(i) == *bit),
+            "The Merkle path index bits do not match the leaf index"
+        );
+        // Retrieve the sibling hashes.
+        let siblings = Vec::<String>::deserialize(&json["siblings"])?
+            .iter()
+            .map(|sibling| Field::from_bytes_le(&hex::decode(sibling)?))
+            .collect::<Result<Vec<_>>>()?;
+        // Recover the Merkle path.
+        Self::try_from((U64::new(leaf_index), siblings))
+    }
+}
+
 impl<E: Environment, const DEPTH: u8> Serialize for MerklePath<E, DEPTH> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         ToBytesSerializer::serialize(self, serializer)
@@ -139,8 +227,100 @@ impl<E: Environment, const DEPTH: u8> Serialize for MerklePath<E, DEPTH> {
 
 impl<'de, E: Environment, const DEPTH: u8> Deserialize<'de> for MerklePath<E, DEPTH> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        // Compute the size for: u64 + (Field::SIZE_IN_BYTES * DEPTH).
-        let size = 8 + DEPTH as usize * (Field::<E>::size_in_bits() + 7) / 8;
-        FromBytesDeserializer::<Self>::deserialize(deserializer, "Merkle path", size)
+        FromBytesDeserializer::<Self>::deserialize(deserializer, "Merkle path", Self::unframed_size_in_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::prelude::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+    const DEPTH: u8 = 32;
+
+    #[test]
+    fn test_bytes_framed() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two new Merkle paths.
+            let mut sample = || {
+                let leaf_index = U64::new(rng.gen_range(0..(1u64 << DEPTH)));
+                let siblings = (0..DEPTH).map(|_| Field::rand(&mut rng)).collect();
+                MerklePath::<CurrentEnvironment, DEPTH>::try_from((leaf_index, siblings))
+            };
+            let (first, second) = (sample()?, sample()?);
+
+            // Check the framed byte representation.
+            let mut bytes = vec![];
+            first.write_le_framed(&mut bytes)?;
+            second.write_le_framed(&mut bytes)?;
+            let mut reader = &bytes[..];
+            assert_eq!(first, MerklePath::read_le_framed(&mut reader)?);
+            assert_eq!(second, MerklePath::read_le_framed(&mut reader)?);
+            assert!(reader.is_empty());
+
+            // Check that the first path can be skipped.
+            let mut reader = &bytes[..];
+            MerklePath::<CurrentEnvironment, DEPTH>::skip_framed(&mut reader)?;
+            assert_eq!(second, MerklePath::read_le_framed(&mut reader)?);
+
+            // Check that a truncated path is rejected.
+            let truncated = &bytes[..bytes.len() - 1];
+            let mut reader = truncated;
+            MerklePath::<CurrentEnvironment, DEPTH>::skip_framed(&mut reader)?;
+            assert!(MerklePath::<CurrentEnvironment, DEPTH>::read_le_framed(&mut reader).is_err());
+            let mut reader = truncated;
+            MerklePath::<CurrentEnvironment, DEPTH>::skip_framed(&mut reader)?;
+            assert!(MerklePath::<CurrentEnvironment, DEPTH>::skip_framed(&mut reader).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new Merkle path.
+            let leaf_index = U64::new(rng.gen_range(0..(1u64 << DEPTH)));
+            let siblings = (0..DEPTH).map(|_| Field::rand(&mut rng)).collect();
+            let expected = MerklePath::<CurrentEnvironment, DEPTH>::try_from((leaf_index, siblings))?;
+
+            // Export
+            let candidate = expected.to_json()?;
+            assert_eq!(candidate["leaf_index"], *expected.leaf_index());
+            assert_eq!(candidate["index_bits"].as_array().unwrap().len(), DEPTH as usize);
+            assert_eq!(candidate["index_bits"][0], *expected.leaf_index() & 1 == 1);
+            assert_eq!(candidate["siblings"][0], hex::encode(expected.siblings()[0].to_bytes_le()?));
+
+            // Import
+            assert_eq!(expected, MerklePath::from_json(&candidate)?);
+
+            // Ensure mismatched index bits are rejected.
+            let mut mismatched = candidate;
+            mismatched["index_bits"][0] = (*expected.leaf_index() & 1 == 0).into();
+            assert!(MerklePath::<CurrentEnvironment, DEPTH>::from_json(&mismatched).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json_is_unchanged() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new Merkle path.
+        let leaf_index = U64::new(rng.gen_range(0..(1u64 << DEPTH)));
+        let siblings = (0..DEPTH).map(|_| Field::rand(&mut rng)).collect();
+        let expected = MerklePath::<CurrentEnvironment, DEPTH>::try_from((leaf_index, siblings))?;
+
+        // Ensure the serde encoding remains the byte encoding.
+        let candidate = serde_json::to_string(&expected)?;
+        assert_eq!(candidate, serde_json::to_string(&expected.to_bytes_le()?)?);
+        assert_eq!(expected, serde_json::from_str(&candidate)?);
+        Ok(())
     }
 }
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+type LH = BHP1024<CurrentEnvironment>;
+type PH = BHP512<CurrentEnvironment>;
+
+const DEPTH: u8 = 8;
+
+#[test]
+fn test_merkle_tree_index_of() -> Result<()> {
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    for num_leaves in [1, 2, 5, 16] {
+        let leaves = (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect_vec();
+        let tree = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+        let indexed_tree = tree.clone().with_leaf_index()?;
+
+        for (leaf_index, leaf) in leaves.iter().enumerate() {
+            // Ensure the index is found, with or without the leaf index.
+            assert_eq!(tree.index_of(leaf)?, Some(leaf_index));
+            assert_eq!(indexed_tree.index_of(leaf)?, Some(leaf_index));
+
+            // Ensure the index produces a verifying path.
+            let proof = indexed_tree.prove(indexed_tree.index_of(leaf)?.unwrap(), leaf)?;
+            assert!(indexed_tree.verify(&proof, indexed_tree.root(), leaf));
+        }
+
+        // Ensure a leaf that is not in the tree is not found.
+        let missing_leaf = Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le();
+        assert_eq!(tree.index_of(&missing_leaf)?, None);
+        assert_eq!(indexed_tree.index_of(&missing_leaf)?, None);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_index_of_after_modification() -> Result<()> {
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+    let mut new_leaf = || Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le();
+
+    let leaves = (0..4).map(|_| new_leaf()).collect_vec();
+    let mut tree =
+        MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?.with_leaf_index()?;
+
+    // Ensure appended leaves are indexed.
+    let appended_leaf = new_leaf();
+    tree.append(&[appended_leaf.clone()])?;
+    assert_eq!(tree.index_of(&appended_leaf)?, Some(4));
+
+    // Ensure an updated leaf replaces the previous one in the index.
+    let updated_leaf = new_leaf();
+    tree.update(1, &updated_leaf)?;
+    assert_eq!(tree.index_of(&updated_leaf)?, Some(1));
+    assert_eq!(tree.index_of(&leaves[1])?, None);
+
+    // Ensure a duplicate leaf resolves to its first occurrence.
+    tree.update_leaf(3, &updated_leaf)?;
+    assert_eq!(tree.index_of(&updated_leaf)?, Some(1));
+
+    // Ensure the remaining occurrence is found once the first one is replaced.
+    tree.update_many(&BTreeMap::from([(0, leaves[1].clone()), (1, leaves[3].clone())]))?;
+    assert_eq!(tree.index_of(&updated_leaf)?, Some(3));
+    assert_eq!(tree.index_of(&leaves[1])?, Some(0));
+    assert_eq!(tree.index_of(&leaves[0])?, None);
+
+    // Ensure the updated leaf index matches a freshly built one.
+    let current_leaves = [&leaves[1], &leaves[3], &leaves[2], &updated_leaf, &appended_leaf].map(Clone::clone);
+    let rebuilt = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &current_leaves)?
+        .with_leaf_index()?;
+    assert_eq!(tree.root(), rebuilt.root());
+    assert_eq!(tree.leaf_index, rebuilt.leaf_index);
+
+    // Ensure removed leaves are no longer indexed.
+    tree.remove_last_n(1)?;
+    assert_eq!(tree.index_of(&appended_leaf)?, None);
+    assert_eq!(tree.index_of(&leaves[1])?, Some(0));
+    Ok(())
+}
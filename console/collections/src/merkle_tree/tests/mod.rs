@@ -15,9 +15,12 @@
 use super::*;
 
 mod append;
+mod index_of;
+mod padding;
 mod remove;
 mod update;
 mod update_many;
+mod verify;
 
 macro_rules! run_tests {
     ($rng:expr, [$($i:expr),*]) => {
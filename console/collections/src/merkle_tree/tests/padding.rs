@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+type LH = BHP1024<CurrentEnvironment>;
+type PH = BHP512<CurrentEnvironment>;
+
+type Tree = MerkleTree<CurrentEnvironment, LH, PH, 8>;
+
+/// Samples the given number of random leaves.
+fn sample_leaves(num_leaves: usize, rng: &mut TestRng) -> Vec<Vec<bool>> {
+    (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect()
+}
+
+#[test]
+fn test_merkle_tree_padding_policy() -> Result<()> {
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    for num_leaves in [1, 2, 3, 5, 8, 13] {
+        let leaves = sample_leaves(num_leaves, &mut rng);
+        let pad = sample_leaves(1, &mut rng).pop().unwrap();
+        let max_leaves = num_leaves.next_power_of_two();
+
+        // Ensure the empty padding policy matches `MerkleTree::new`.
+        let tree = Tree::new(&leaf_hasher, &path_hasher, &leaves)?;
+        let empty = Tree::new_with_padding(&leaf_hasher, &path_hasher, &leaves, &PaddingPolicy::Empty)?;
+        assert_eq!(tree.root(), empty.root());
+
+        // Ensure repeating the last leaf matches a tree with the last leaf explicitly repeated.
+        let repeat_last = Tree::new_with_padding(&leaf_hasher, &path_hasher, &leaves, &PaddingPolicy::RepeatLast)?;
+        let mut expected_leaves = leaves.clone();
+        expected_leaves.resize(max_leaves, leaves[num_leaves - 1].clone());
+        let expected = Tree::new(&leaf_hasher, &path_hasher, &expected_leaves)?;
+        assert_eq!(repeat_last.root(), expected.root());
+
+        // Ensure an explicit pad matches a tree with the pad explicitly appended.
+        let explicit =
+            Tree::new_with_padding(&leaf_hasher, &path_hasher, &leaves, &PaddingPolicy::ExplicitPad(pad.clone()))?;
+        let mut expected_leaves = leaves.clone();
+        expected_leaves.resize(max_leaves, pad);
+        let expected = Tree::new(&leaf_hasher, &path_hasher, &expected_leaves)?;
+        assert_eq!(explicit.root(), expected.root());
+
+        // Ensure the padding differs from the empty hash unless the leaves are already a power of two.
+        assert_eq!(num_leaves == max_leaves, tree.root() == explicit.root());
+
+        // Ensure every leaf can be proven, and the padding leaves are not counted.
+        for tree in [&repeat_last, &explicit] {
+            assert_eq!(tree.number_of_leaves(), num_leaves);
+            for (leaf_index, leaf) in leaves.iter().enumerate() {
+                let proof = tree.prove(leaf_index, leaf)?;
+                assert!(tree.verify(&proof, tree.root(), leaf));
+            }
+        }
+
+        // Ensure an explicitly padded tree cannot be modified.
+        let new_leaf = sample_leaves(1, &mut rng).pop().unwrap();
+        assert!(explicit.prepare_append(&[new_leaf.clone()]).is_err());
+        assert!(explicit.prepare_update(0, &new_leaf).is_err());
+        assert!(explicit.prepare_remove_last_n(1).is_err());
+        assert!(tree.prepare_append(&[new_leaf]).is_ok());
+    }
+    Ok(())
+}
@@ -74,6 +74,7 @@ fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash
 /// 2. Apply the updates to the Merkle tree.
 /// 3. Construct a new Merkle tree with the only the updated leaves.
 /// 4. Check that the Merkle root of the new Merkle tree is the same as the Merkle root of the original Merkle tree.
+/// 5. Check that applying the updates in place yields the same Merkle tree and root.
 fn check_updated_merkle_tree_is_consistent<
     E: Environment,
     LH: LeafHash<Hash = PH::Hash>,
@@ -88,6 +89,7 @@ fn check_updated_merkle_tree_is_consistent<
     // Construct the Merkle tree for the given leaves.
     let mut merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &leaves)?;
     assert_eq!(leaves.len(), merkle_tree.number_of_leaves);
+    let mut in_place_merkle_tree = merkle_tree.clone();
 
     // Construct an index map to track the leaves.
     let mut leaf_map: IndexMap<usize, LH::Leaf> = leaves.into_iter().enumerate().collect::<IndexMap<usize, LH::Leaf>>();
@@ -98,8 +100,8 @@ fn check_updated_merkle_tree_is_consistent<
     }
 
     // Add the updated leaves to the index map.
-    for (index, leaf) in updates {
-        leaf_map.insert(index, leaf);
+    for (index, leaf) in &updates {
+        leaf_map.insert(*index, leaf.clone());
     }
 
     // Get the updated leaves.
@@ -110,6 +112,17 @@ fn check_updated_merkle_tree_is_consistent<
 
     // Check that the Merkle root of the new Merkle tree is the same as the Merkle root of the original Merkle tree.
     assert_eq!(merkle_tree.root(), updated_merkle_tree.root());
+
+    // Apply the updates to the Merkle tree in place.
+    let mut root = *in_place_merkle_tree.root();
+    for (index, leaf) in &updates {
+        root = in_place_merkle_tree.update_leaf(*index, leaf)?;
+    }
+
+    // Check that the in-place updates match the new Merkle tree.
+    assert_eq!(&root, updated_merkle_tree.root());
+    assert_eq!(in_place_merkle_tree.root(), updated_merkle_tree.root());
+    assert_eq!(in_place_merkle_tree.tree(), updated_merkle_tree.tree());
     Ok(())
 }
 
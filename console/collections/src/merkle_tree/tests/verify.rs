@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+type LH = BHP1024<CurrentEnvironment>;
+type PH = BHP512<CurrentEnvironment>;
+
+const DEPTH: u8 = 8;
+
+#[test]
+fn test_merkle_path_verify_with_hashes() -> Result<()> {
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    for num_leaves in [1, 2, 5, 16] {
+        let leaves = (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect_vec();
+        let tree = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+
+        for (leaf_index, leaf) in leaves.iter().enumerate() {
+            let proof = tree.prove(leaf_index, leaf)?;
+
+            // Ensure the hashes walk from the leaf hash to the root.
+            let (is_valid, hashes) = proof.verify_with_hashes(&leaf_hasher, &path_hasher, tree.root(), leaf)?;
+            assert!(is_valid);
+            assert!(proof.verify(&leaf_hasher, &path_hasher, tree.root(), leaf));
+            assert_eq!(hashes.len(), DEPTH as usize + 1);
+            assert_eq!(hashes[0], leaf_hasher.hash_leaf(leaf)?);
+            assert_eq!(hashes[DEPTH as usize], *tree.root());
+
+            // Ensure an incorrect root is rejected, while computing the same hashes.
+            let root = Field::rand(&mut rng);
+            let (is_valid, candidate_hashes) = proof.verify_with_hashes(&leaf_hasher, &path_hasher, &root, leaf)?;
+            assert!(!is_valid);
+            assert!(!proof.verify(&leaf_hasher, &path_hasher, &root, leaf));
+            assert_eq!(candidate_hashes, hashes);
+
+            // Ensure an incorrect leaf is rejected.
+            let other_leaf = Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le();
+            let (is_valid, _) = proof.verify_with_hashes(&leaf_hasher, &path_hasher, tree.root(), &other_leaf)?;
+            assert!(!is_valid);
+            assert!(!proof.verify(&leaf_hasher, &path_hasher, tree.root(), &other_leaf));
+        }
+    }
+    Ok(())
+}
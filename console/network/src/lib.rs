@@ -190,6 +190,9 @@ pub trait Network:
     const MAX_PROGRAM_DEPTH: usize = 64;
     /// The maximum number of imports.
     const MAX_IMPORTS: usize = 64;
+    /// The program names that may not be imported on this network.
+    /// By default, every import is permitted.
+    const RESERVED_IMPORT_NAMES: &'static [&'static str] = &[];
 
     /// The maximum number of certificates in a batch.
     const MAX_CERTIFICATES: u16;
@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use std::{
+    collections::HashMap,
+    sync::{RwLock, RwLockReadGuard},
+};
+
+/// The map of `(previous_block_hash, header_root)` to block hash.
+type BlockHashes<N> = HashMap<(Field<N>, Field<N>), Field<N>>;
+
+/// A thread-safe memoization of block hashes, keyed on the previous block hash and block header root.
+/// This is intended to be shared while verifying a batch of state paths, and dropped afterwards.
+pub struct BlockHashCache<N: Network> {
+    /// The cached block hashes.
+    block_hashes: RwLock<BlockHashes<N>>,
+}
+
+impl<N: Network> Default for BlockHashCache<N> {
+    /// Initializes a new, empty block hash cache.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> BlockHashCache<N> {
+    /// Initializes a new, empty block hash cache.
+    pub fn new() -> Self {
+        Self { block_hashes: Default::default() }
+    }
+
+    /// Returns the block hash for the given previous block hash and block header root,
+    /// computing and caching it if it is not yet in the cache.
+    pub fn block_hash(&self, previous_block_hash: Field<N>, header_root: Field<N>) -> Result<Field<N>> {
+        // Return the block hash, if it is cached.
+        if let Some(block_hash) = self.read()?.get(&(previous_block_hash, header_root)) {
+            return Ok(*block_hash);
+        }
+        // Compute the block hash.
+        let block_hash = N::hash_bhp1024(&to_bits_le![previous_block_hash, header_root])?;
+        // Cache the block hash.
+        self.block_hashes
+            .write()
+            .map_err(|_| anyhow!("The block hash cache is poisoned"))?
+            .insert((previous_block_hash, header_root), block_hash);
+        Ok(block_hash)
+    }
+
+    /// Returns the number of cached block hashes.
+    pub fn len(&self) -> usize {
+        self.read().map(|block_hashes| block_hashes.len()).unwrap_or(0)
+    }
+
+    /// Returns `true` if there are no cached block hashes.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a read guard over the cached block hashes.
+    fn read(&self) -> Result<RwLockReadGuard<BlockHashes<N>>> {
+        self.block_hashes.read().map_err(|_| anyhow!("The block hash cache is poisoned"))
+    }
+}
@@ -115,4 +115,56 @@ mod tests {
             assert!(StatePath::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         }
     }
+
+    #[test]
+    fn test_out_of_range_commitment() {
+        let mut rng = TestRng::default();
+
+        // Sample the state path.
+        let state_path =
+            crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, &mut rng).unwrap();
+        let mut bytes = state_path.to_bytes_le().unwrap();
+
+        // Overwrite the commitment, which is the ID of the trailing transition leaf, with a value above the modulus.
+        let num_bytes = bytes.len();
+        bytes[num_bytes - Field::<CurrentNetwork>::size_in_bytes()..].fill(u8::MAX);
+
+        // Ensure the out-of-range commitment is rejected before it can be used as a Merkle leaf.
+        assert!(StatePath::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
+
+    /// Returns the expected size of a serialized state path, derived from the tree depths and the field size.
+    fn expected_size_in_bytes<N: Network>() -> usize {
+        let field = Field::<N>::size_in_bytes();
+        // A Merkle path is a `u64` leaf index followed by one field element per level.
+        let path = |depth: u8| 8 + depth as usize * field;
+
+        1 // version
+            + field // global_state_root
+            + path(BLOCKS_DEPTH)
+            + 3 * field // block_hash, previous_block_hash, header_root
+            + path(HEADER_DEPTH)
+            + (1 + field) // header_leaf
+            + path(TRANSACTIONS_DEPTH)
+            + field // transaction_id
+            + path(TRANSACTION_DEPTH)
+            + (1 + 2 + field) // transaction_leaf
+            + 2 * field // transition_root, tcm
+            + path(TRANSITION_DEPTH)
+            + (1 + 1 + 1 + field) // transition_leaf
+    }
+
+    fn check_size_in_bytes<N: Network>(rng: &mut TestRng) {
+        let state_path = crate::state_path::test_helpers::sample_global_state_path::<N>(None, rng).unwrap();
+        assert_eq!(state_path.to_bytes_le().unwrap().len(), expected_size_in_bytes::<N>());
+    }
+
+    #[test]
+    fn test_size_in_bytes() {
+        let mut rng = TestRng::default();
+
+        // Ensure the byte representation has the expected size on every network.
+        check_size_in_bytes::<CurrentNetwork>(&mut rng);
+        check_size_in_bytes::<snarkvm_console_network::TestnetV0>(&mut rng);
+    }
 }
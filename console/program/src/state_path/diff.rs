@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::fmt::Write;
+
+/// A field that differs between two state paths.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The value of the field in `self`.
+    pub left: String,
+    /// The value of the field in `other`.
+    pub right: String,
+}
+
+impl<N: Network> StatePath<N> {
+    /// Returns the fields that differ between `self` and `other`, in declaration order.
+    /// Fields are compared by their byte representation, and Merkle paths are reported in hex.
+    pub fn diff(&self, other: &Self) -> Result<Vec<FieldDiff>> {
+        let mut diffs = Vec::new();
+        compare(&mut diffs, "global_state_root", &self.global_state_root, &other.global_state_root, display)?;
+        compare(&mut diffs, "block_path", &self.block_path, &other.block_path, hex)?;
+        compare(&mut diffs, "block_hash", &self.block_hash, &other.block_hash, display)?;
+        compare(&mut diffs, "previous_block_hash", &self.previous_block_hash, &other.previous_block_hash, display)?;
+        compare(&mut diffs, "header_root", &self.header_root, &other.header_root, display)?;
+        compare(&mut diffs, "header_path", &self.header_path, &other.header_path, hex)?;
+        compare(&mut diffs, "header_leaf", &self.header_leaf, &other.header_leaf, display)?;
+        compare(&mut diffs, "transactions_path", &self.transactions_path, &other.transactions_path, hex)?;
+        compare(&mut diffs, "transaction_id", &self.transaction_id, &other.transaction_id, display)?;
+        compare(&mut diffs, "transaction_path", &self.transaction_path, &other.transaction_path, hex)?;
+        compare(&mut diffs, "transaction_leaf", &self.transaction_leaf, &other.transaction_leaf, display)?;
+        compare(&mut diffs, "transition_root", &self.transition_root, &other.transition_root, display)?;
+        compare(&mut diffs, "tcm", &self.tcm, &other.tcm, display)?;
+        compare(&mut diffs, "transition_path", &self.transition_path, &other.transition_path, hex)?;
+        compare(&mut diffs, "transition_leaf", &self.transition_leaf, &other.transition_leaf, display)?;
+        Ok(diffs)
+    }
+}
+
+/// Appends a `FieldDiff` to `diffs` if the byte representations of `left` and `right` differ.
+fn compare<T: ToBytes>(
+    diffs: &mut Vec<FieldDiff>,
+    name: &'static str,
+    left: &T,
+    right: &T,
+    render: impl Fn(&T, &[u8]) -> String,
+) -> Result<()> {
+    let (left_bytes, right_bytes) = (left.to_bytes_le()?, right.to_bytes_le()?);
+    if left_bytes != right_bytes {
+        diffs.push(FieldDiff { name, left: render(left, &left_bytes), right: render(right, &right_bytes) });
+    }
+    Ok(())
+}
+
+/// Renders a value using its `Display` implementation.
+fn display<T: Display>(value: &T, _bytes: &[u8]) -> String {
+    value.to_string()
+}
+
+/// Renders a value as its hex-encoded bytes.
+fn hex<T>(_value: &T, bytes: &[u8]) -> String {
+    bytes.iter().fold(String::with_capacity(2 * bytes.len()), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::{prelude::TestRng, MainnetV0};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_diff() {
+        let rng = &mut TestRng::default();
+
+        // Sample two state paths.
+        let first = crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+        let second = crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+
+        // Ensure a state path does not differ from itself.
+        assert!(first.diff(&first).unwrap().is_empty());
+        assert!(first.diff(&first.clone()).unwrap().is_empty());
+
+        // Ensure the differing fields are reported by name, with their values.
+        let diffs = first.diff(&second).unwrap();
+        let global_state_root = diffs.iter().find(|diff| diff.name == "global_state_root").unwrap();
+        assert_eq!(global_state_root.left, first.global_state_root().to_string());
+        assert_eq!(global_state_root.right, second.global_state_root().to_string());
+        // Ensure Merkle paths are rendered in hex.
+        assert_eq!(hex(first.block_path(), &[0x00, 0x0a, 0xff]), "000aff");
+
+        // Ensure the diff is symmetric.
+        let reversed = second.diff(&first).unwrap();
+        assert_eq!(diffs.len(), reversed.len());
+        for (diff, reversed) in diffs.iter().zip_eq(&reversed) {
+            assert_eq!(diff.name, reversed.name);
+            assert_eq!(diff.left, reversed.right);
+            assert_eq!(diff.right, reversed.left);
+        }
+    }
+}
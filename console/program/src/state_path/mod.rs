@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod block_hash_cache;
+pub use block_hash_cache::*;
+
 mod configuration;
 pub use configuration::*;
 
+mod diff;
+pub use diff::*;
+
 mod header_leaf;
 pub use header_leaf::*;
 
@@ -52,11 +52,54 @@ impl<N: Network> StatePath<N> {
     ///                                                                          transition_leaf
     /// ```
     pub fn verify(&self, is_global: bool, local_state_root: Field<N>) -> Result<()> {
+        self.verify_internal(is_global, local_state_root, None)
+    }
+
+    /// Checks if the state path is valid, using the given `cache` to avoid recomputing the block hash.
+    /// This is useful when verifying many state paths that belong to the same block.
+    pub fn verify_with_cache(
+        &self,
+        is_global: bool,
+        local_state_root: Field<N>,
+        cache: &BlockHashCache<N>,
+    ) -> Result<()> {
+        self.verify_internal(is_global, local_state_root, Some(cache))
+    }
+
+    /// Checks if the state path is valid, optionally using the given `cache` for the block hash.
+    fn verify_internal(
+        &self,
+        is_global: bool,
+        local_state_root: Field<N>,
+        cache: Option<&BlockHashCache<N>>,
+    ) -> Result<()> {
+        // Verify the inclusion proofs concurrently, as they are independent of each other.
+        // Their results are checked below in a fixed order, so the reported error is deterministic.
+        let transition_path = || {
+            N::verify_merkle_path_bhp(&self.transition_path, &self.transition_root, &self.transition_leaf.to_bits_le())
+        };
+        let transaction_path = || {
+            N::verify_merkle_path_bhp(&self.transaction_path, &self.transaction_id, &self.transaction_leaf.to_bits_le())
+        };
+        let transactions_path = || {
+            is_global
+                && N::verify_merkle_path_bhp(
+                    &self.transactions_path,
+                    &self.header_leaf.id(),
+                    &self.transaction_id.to_bits_le(),
+                )
+        };
+        let header_path = || {
+            is_global && N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le())
+        };
+        let ((is_transition_path_valid, is_transaction_path_valid), (is_transactions_path_valid, is_header_path_valid)) =
+            join(|| join(transition_path, transaction_path), || join(transactions_path, header_path));
+
         // Ensure the transition leaf variant is 3 (Input::Record).
         ensure!(self.transition_leaf.variant() == 3, "Transition leaf variant must be 3 (Input::Record)");
         // Ensure the transition path is valid.
         ensure!(
-            N::verify_merkle_path_bhp(&self.transition_path, &self.transition_root, &self.transition_leaf.to_bits_le()),
+            is_transition_path_valid,
             "'{}' (an input or output ID) does not belong to '{}' (a function or transition)",
             self.transition_leaf.id(),
             self.transaction_leaf.id()
@@ -73,11 +116,7 @@ impl<N: Network> StatePath<N> {
         ensure!(self.transaction_leaf.variant() == 1, "Transaction leaf variant must be 1 (Transaction::Execution)");
         // Ensure the transaction path is valid.
         ensure!(
-            N::verify_merkle_path_bhp(
-                &self.transaction_path,
-                &self.transaction_id,
-                &self.transaction_leaf.to_bits_le()
-            ),
+            is_transaction_path_valid,
             "'{}' (a function or transition) does not belong to transaction '{}'",
             self.transaction_leaf.id(),
             self.transaction_id
@@ -88,25 +127,26 @@ impl<N: Network> StatePath<N> {
             ensure!(self.header_leaf.index() == 1, "Header leaf index must be 1 (Header::transactions_root)");
             // Ensure the transactions path is valid.
             ensure!(
-                N::verify_merkle_path_bhp(
-                    &self.transactions_path,
-                    &self.header_leaf.id(),
-                    &self.transaction_id.to_bits_le()
-                ),
+                is_transactions_path_valid,
                 "Transaction '{}' does not belong to '{}' (a header leaf)",
                 self.transaction_id,
                 self.header_leaf
             );
             // Ensure the header path is valid.
             ensure!(
-                N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()),
+                is_header_path_valid,
                 "'{}' (a header leaf) does not belong to '{}' (a block header)",
                 self.header_leaf,
                 self.block_hash
             );
+            // Compute the block hash.
+            let block_hash = match cache {
+                Some(cache) => cache.block_hash(*self.previous_block_hash, self.header_root)?,
+                None => N::hash_bhp1024(&to_bits_le![(*self.previous_block_hash), self.header_root])?,
+            };
             // Ensure the block hash is correct.
             ensure!(
-                *self.block_hash == N::hash_bhp1024(&to_bits_le![(*self.previous_block_hash), self.header_root])?,
+                *self.block_hash == block_hash,
                 "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
                 self.block_hash
             );
@@ -130,6 +170,18 @@ impl<N: Network> StatePath<N> {
     }
 }
 
+/// Runs the given closures, in parallel unless the `serial` feature is enabled.
+fn join<A: Send, B: Send>(a: impl FnOnce() -> A + Send, b: impl FnOnce() -> B + Send) -> (A, B) {
+    #[cfg(not(feature = "serial"))]
+    {
+        rayon::join(a, b)
+    }
+    #[cfg(feature = "serial")]
+    {
+        (a(), b())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,6 +209,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_verify_global_with_cache() {
+        let rng = &mut TestRng::default();
+
+        let cache = BlockHashCache::<CurrentNetwork>::new();
+        assert!(cache.is_empty());
+
+        for i in 0..ITERATIONS {
+            // Sample the state path.
+            let state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+            // Sample the local state root.
+            let local_state_root = Field::rand(rng);
+
+            // Ensure the state path is valid, and only computes the block hash once.
+            state_path.verify_with_cache(true, local_state_root, &cache).unwrap();
+            state_path.verify_with_cache(true, local_state_root, &cache).unwrap();
+            assert_eq!(cache.len(), i + 1);
+            // Ensure the state path is *not* valid for a random local state root.
+            state_path.verify_with_cache(false, local_state_root, &cache).unwrap_err();
+        }
+    }
+
     #[test]
     fn test_verify_local() {
         let rng = &mut TestRng::default();
@@ -214,4 +289,45 @@ mod tests {
             new_local_state_path.verify(true, Field::rand(rng)).unwrap_err();
         }
     }
+
+    #[test]
+    fn test_verify_reports_first_failure() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample two state paths.
+            let a = crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+            let b = crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+
+            // Construct a state path whose transition path and header path are both invalid.
+            let state_path = StatePath::from(
+                a.global_state_root(),
+                a.block_path().clone(),
+                a.block_hash(),
+                a.previous_block_hash(),
+                *a.header_root(),
+                b.header_path().clone(),
+                *a.header_leaf(),
+                a.transactions_path().clone(),
+                *a.transaction_id(),
+                a.transaction_path().clone(),
+                *a.transaction_leaf(),
+                *a.transition_root(),
+                *a.tcm(),
+                b.transition_path().clone(),
+                *a.transition_leaf(),
+            );
+
+            // Ensure the transition path failure is always the one reported.
+            let expected = format!(
+                "'{}' (an input or output ID) does not belong to '{}' (a function or transition)",
+                a.transition_leaf().id(),
+                a.transaction_leaf().id()
+            );
+            for _ in 0..3 {
+                let error = state_path.verify(true, Field::rand(rng)).unwrap_err();
+                assert_eq!(error.to_string(), expected);
+            }
+        }
+    }
 }
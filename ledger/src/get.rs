@@ -35,6 +35,21 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_state_path_for_commitment(commitment)
     }
 
+    /// Returns the state paths for the given commitments, in order.
+    pub fn get_state_paths_for_commitments(&self, commitments: &[Field<N>]) -> Result<Vec<StatePath<N>>> {
+        self.vm.block_store().get_state_paths_for_commitments(commitments)
+    }
+
+    /// Returns a state path for the given commitment, anchored at the state root of the given block height.
+    /// This proves the commitment was included at or before a historical block, rather than the latest one.
+    pub fn get_historical_state_path_for_commitment(
+        &self,
+        commitment: &Field<N>,
+        block_height: u32,
+    ) -> Result<StatePath<N>> {
+        self.vm.block_store().get_historical_state_path_for_commitment(commitment, block_height)
+    }
+
     /// Returns the epoch hash for the given block height.
     pub fn get_epoch_hash(&self, block_height: u32) -> Result<N::BlockHash> {
         // Compute the epoch number from the current block height.
@@ -23,7 +23,7 @@ use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
-    types::U16,
+    types::{Field, U16},
 };
 use ledger_block::{ConfirmedTransaction, Ratify, Rejected, Transaction};
 use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
@@ -85,7 +85,71 @@ fn test_state_path() {
     let commitments = block.transactions().commitments().collect::<Vec<_>>();
     let commitment = commitments[0];
 
-    let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
+    let state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
+    // Ensure the state path is valid against the latest state root.
+    assert_eq!(state_path.global_state_root(), ledger.latest_state_root());
+    state_path.verify(true, Default::default()).unwrap();
+
+    // Construct the state paths for every commitment at once, and ensure they match the individual state paths.
+    let commitments = commitments.into_iter().copied().collect::<Vec<_>>();
+    let state_paths = ledger.get_state_paths_for_commitments(&commitments).unwrap();
+    assert_eq!(state_paths.len(), commitments.len());
+    for (commitment, state_path) in commitments.iter().zip(state_paths) {
+        assert_eq!(state_path, ledger.get_state_path_for_commitment(commitment).unwrap());
+    }
+
+    // Ensure a commitment that is not in the ledger is rejected with a clear error.
+    let missing_commitment = Field::<CurrentNetwork>::rand(rng);
+    let error = ledger.get_state_path_for_commitment(&missing_commitment).unwrap_err();
+    assert_eq!(error.to_string(), format!("Commitment '{missing_commitment}' does not exist"));
+    assert!(ledger.get_state_paths_for_commitments(&[commitments[0], missing_commitment]).is_err());
+}
+
+#[test]
+fn test_historical_state_path() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve a commitment from the genesis block.
+    let genesis = ledger.get_block(0).unwrap();
+    let commitment = *genesis.transactions().commitments().next().unwrap();
+
+    // Advance the ledger by two blocks.
+    for _ in 0..2 {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+        let transaction = ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.check_next_block(&block, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_eq!(ledger.latest_height(), 2);
+
+    // Ensure the state path at each height is anchored at the state root of that height.
+    for height in 0..=2 {
+        let state_path = ledger.get_historical_state_path_for_commitment(&commitment, height).unwrap();
+        assert_eq!(Some(state_path.global_state_root()), ledger.get_state_root(height).unwrap());
+        state_path.verify(true, Default::default()).unwrap();
+    }
+    // Ensure the state path at the latest height matches the latest state path.
+    assert_eq!(
+        ledger.get_historical_state_path_for_commitment(&commitment, 2).unwrap(),
+        ledger.get_state_path_for_commitment(&commitment).unwrap()
+    );
+
+    // Ensure a state path cannot be anchored at a future block.
+    assert!(ledger.get_historical_state_path_for_commitment(&commitment, 3).is_err());
+
+    // Ensure a state path cannot be anchored at a block before the commitment was included.
+    let block = ledger.get_block(1).unwrap();
+    let commitment = *block.transactions().commitments().next().unwrap();
+    assert!(ledger.get_historical_state_path_for_commitment(&commitment, 1).is_ok());
+    assert!(ledger.get_historical_state_path_for_commitment(&commitment, 0).is_err());
 }
 
 #[test]
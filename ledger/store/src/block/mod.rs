@@ -27,7 +27,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{BlockPath, BlockTree, HeaderLeaf, HeaderPath, ProgramID, StatePath},
     types::Field,
 };
 use ledger_authority::Authority;
@@ -48,11 +48,15 @@ use synthesizer_program::{FinalizeOperation, Program};
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use parking_lot::RwLock;
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The retrieved blocks, keyed by their hash, with their block path, header root, header leaf, and header path.
+type BlocksWithPaths<N> =
+    HashMap<<N as Network>::BlockHash, (Block<N>, BlockPath<N>, Field<N>, HeaderLeaf<N>, HeaderPath<N>)>;
+
 /// Separates the confirmed transaction into a tuple.
 #[allow(clippy::type_complexity)]
 fn to_confirmed_tuple<N: Network>(
@@ -648,6 +652,34 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns a state path for the given `commitment`.
     fn get_state_path_for_commitment(&self, commitment: &Field<N>, block_tree: &BlockTree<N>) -> Result<StatePath<N>> {
+        match self.get_state_paths_for_commitments(std::slice::from_ref(commitment), block_tree)?.pop() {
+            Some(state_path) => Ok(state_path),
+            None => bail!("The state path for commitment '{commitment}' is missing"),
+        }
+    }
+
+    /// Returns the state paths for the given `commitments`, in order.
+    /// The block path and block header path of each block are only constructed once.
+    fn get_state_paths_for_commitments(
+        &self,
+        commitments: &[Field<N>],
+        block_tree: &BlockTree<N>,
+    ) -> Result<Vec<StatePath<N>>> {
+        // Initialize a map of each retrieved block to its block path, header root, header leaf, and header path.
+        let mut blocks = BlocksWithPaths::<N>::new();
+        commitments
+            .iter()
+            .map(|commitment| self.get_state_path_with_blocks(commitment, block_tree, &mut blocks))
+            .collect()
+    }
+
+    /// Returns a state path for the given `commitment`, reusing the paths of any block in `blocks`.
+    fn get_state_path_with_blocks(
+        &self,
+        commitment: &Field<N>,
+        block_tree: &BlockTree<N>,
+        blocks: &mut BlocksWithPaths<N>,
+    ) -> Result<StatePath<N>> {
         // Ensure the commitment exists.
         if !self.transition_store().contains_commitment(commitment)? {
             bail!("Commitment '{commitment}' does not exist");
@@ -671,15 +703,31 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(transition) => transition,
             None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage"),
         };
-        // Retrieve the block.
-        let block = match self.get_block(&block_hash)? {
-            Some(block) => block,
-            None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
+        // Retrieve the block, and construct its block path and block header path, if they were not already.
+        let (block, block_path, header_root, header_leaf, header_path) = match blocks.entry(block_hash) {
+            std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                // Retrieve the block.
+                let block = match self.get_block(&block_hash)? {
+                    Some(block) => block,
+                    None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
+                };
+
+                // Construct the block path.
+                let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+
+                // Construct the block header path.
+                let block_header = block.header();
+                let header_root = block_header.to_root()?;
+                let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
+                let header_path = block_header.to_path(&header_leaf)?;
+
+                entry.insert((block, block_path, header_root, header_leaf, header_path))
+            }
         };
 
-        // Construct the global state root and block path.
+        // Construct the global state root.
         let global_state_root = *block_tree.root();
-        let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
 
         // Ensure the global state root exists in storage.
         if !self.reverse_state_root_map().contains_key_confirmed(&global_state_root.into())? {
@@ -706,20 +754,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         let transaction_leaf = transaction.to_leaf(transition.id())?;
         let transaction_path = transaction.to_path(&transaction_leaf)?;
 
-        // Construct the block header path.
-        let block_header = block.header();
-        let header_root = block_header.to_root()?;
-        let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
-        let header_path = block_header.to_path(&header_leaf)?;
-
         Ok(StatePath::from(
             global_state_root.into(),
-            block_path,
+            block_path.clone(),
             block.hash(),
             block.previous_hash(),
-            header_root,
-            header_path,
-            header_leaf,
+            *header_root,
+            header_path.clone(),
+            *header_leaf,
             transactions_path,
             transaction.id(),
             transaction_path,
@@ -1209,6 +1251,34 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_state_path_for_commitment(commitment, &self.tree.read())
     }
 
+    /// Returns the state paths for the given `commitments`, in order.
+    pub fn get_state_paths_for_commitments(&self, commitments: &[Field<N>]) -> Result<Vec<StatePath<N>>> {
+        self.storage.get_state_paths_for_commitments(commitments, &self.tree.read())
+    }
+
+    /// Returns a state path for the given `commitment`, anchored at the state root of the given `block height`
+    /// rather than the latest state root. The commitment must be included in a block at or below that height.
+    pub fn get_historical_state_path_for_commitment(
+        &self,
+        commitment: &Field<N>,
+        block_height: u32,
+    ) -> Result<StatePath<N>> {
+        // Reconstruct the block tree as it was at the given block height.
+        let tree = self.tree.read();
+        let num_blocks = u32::try_from(tree.number_of_leaves())?;
+        ensure!(block_height < num_blocks, "Block {block_height} does not exist in storage");
+        let historical_tree = match num_blocks - 1 - block_height {
+            0 => tree.clone(),
+            n => tree.prepare_remove_last_n(usize::try_from(n)?)?,
+        };
+        drop(tree);
+
+        // Construct the state path against the historical block tree.
+        self.storage.get_state_path_for_commitment(commitment, &historical_tree).map_err(|error| {
+            anyhow!("Failed to construct a state path for '{commitment}' at block {block_height}: {error}")
+        })
+    }
+
     /// Returns the previous block hash of the given `block height`.
     pub fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
         self.storage.get_previous_block_hash(height)
@@ -32,6 +32,9 @@ impl<N: Network> Stack<N> {
             program_depth: 0,
         };
 
+        // Ensure the program imports are permitted by the network.
+        program.check_imports_permitted()?;
+
         // Add all the imports into the stack.
         for import in program.imports().keys() {
             // Ensure the program imports all exist in the process already.
@@ -46,7 +49,10 @@ impl<N: Network> Stack<N> {
             stack.program_depth = std::cmp::max(stack.program_depth, external_stack.program_depth() + 1);
             ensure!(
                 stack.program_depth <= N::MAX_PROGRAM_DEPTH,
-                "Program depth exceeds the maximum allowed call depth"
+                "Program depth of '{}' exceeds the maximum allowed call depth ({} > {})",
+                program.id(),
+                stack.program_depth,
+                N::MAX_PROGRAM_DEPTH
             );
         }
         // Add the program closures to the stack.
@@ -2525,8 +2525,12 @@ fn test_long_import_chain() {
         CurrentNetwork::MAX_PROGRAM_DEPTH + 1
     ))
     .unwrap();
-    let result = process.add_program(&program);
-    assert!(result.is_err());
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().contains(&format!(
+        "exceeds the maximum allowed call depth ({} > {})",
+        CurrentNetwork::MAX_PROGRAM_DEPTH + 1,
+        CurrentNetwork::MAX_PROGRAM_DEPTH
+    )));
 }
 
 #[test]
@@ -14,11 +14,58 @@
 
 use super::*;
 
+/// A reader that counts the bytes read through it.
+struct CountingReader<R> {
+    reader: R,
+    count: usize,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let num_bytes = self.reader.read(buf)?;
+        self.count += num_bytes;
+        Ok(num_bytes)
+    }
+}
+
+impl<N: Network> Import<N> {
+    /// Reads the import from a buffer, and returns it along with the number of bytes consumed.
+    pub fn read_le_counted<R: Read>(reader: R) -> IoResult<(Self, usize)> {
+        let mut reader = CountingReader { reader, count: 0 };
+        let id = ProgramID::read_le(&mut reader)?;
+        Ok((Self { program_id: id }, reader.count))
+    }
+
+    /// Reads a length-prefixed list of imports from a buffer.
+    pub fn read_le_vec<R: Read>(mut reader: R) -> IoResult<Vec<Self>> {
+        // Read the number of imports.
+        let num_imports = u16::read_le(&mut reader)?;
+        // Read the imports.
+        let mut imports = Vec::with_capacity(num_imports as usize);
+        for index in 0..num_imports {
+            let import = Self::read_le(&mut reader)
+                .map_err(|e| error(format!("Failed to read import {index} of {num_imports}: {e}")))?;
+            imports.push(import);
+        }
+        Ok(imports)
+    }
+
+    /// Writes a length-prefixed list of imports to a buffer.
+    pub fn write_le_vec<W: Write>(imports: &[Self], mut writer: W) -> IoResult<()> {
+        // Write the number of imports.
+        u16::try_from(imports.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the imports.
+        for import in imports {
+            import.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
 impl<N: Network> FromBytes for Import<N> {
     /// Reads the import from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let id = ProgramID::read_le(&mut reader)?;
-        Ok(Self { program_id: id })
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_counted(reader).map(|(import, _)| import)
     }
 }
 
@@ -28,3 +75,54 @@ impl<N: Network> ToBytes for Import<N> {
         self.program_id.write_le(&mut writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_read_le_counted() -> Result<()> {
+        let expected = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // Ensure the count covers exactly the import, and stops before any trailing data.
+        let mut bytes = expected_bytes.clone();
+        bytes.extend([0xde, 0xad, 0xbe, 0xef]);
+        let (candidate, num_bytes) = Import::<CurrentNetwork>::read_le_counted(&bytes[..])?;
+        assert_eq!(candidate, expected);
+        assert_eq!(num_bytes, expected_bytes.len());
+        assert_eq!(&bytes[num_bytes..], [0xde, 0xad, 0xbe, 0xef]);
+
+        // Ensure a truncated buffer fails.
+        assert!(Import::<CurrentNetwork>::read_le_counted(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_vec() -> Result<()> {
+        let expected = vec![
+            Import::<CurrentNetwork>::from_str("import bar.aleo;")?,
+            Import::<CurrentNetwork>::from_str("import foo.aleo;")?,
+            Import::<CurrentNetwork>::from_str("import baz.aleo;")?,
+        ];
+
+        // Check the byte representation.
+        let mut expected_bytes = Vec::new();
+        Import::write_le_vec(&expected, &mut expected_bytes)?;
+        assert_eq!(expected, Import::read_le_vec(&expected_bytes[..])?);
+
+        // Check that an empty list round-trips.
+        let mut empty_bytes = Vec::new();
+        Import::<CurrentNetwork>::write_le_vec(&[], &mut empty_bytes)?;
+        assert!(Import::<CurrentNetwork>::read_le_vec(&empty_bytes[..])?.is_empty());
+
+        // Check that a truncated buffer fails.
+        for length in 0..expected_bytes.len() {
+            assert!(Import::<CurrentNetwork>::read_le_vec(&expected_bytes[..length]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::num::NonZeroUsize;
+
+/// A cache of resolved programs, keyed by import, in front of a program registry.
+/// Once the cache is full, the least recently used program is evicted.
+pub struct ProgramCache<N: Network, R: AsyncProgramRegistry<N>> {
+    /// The underlying program registry.
+    registry: R,
+    /// The resolved programs, in order of use.
+    programs: Mutex<LruCache<Import<N>, Program<N>>>,
+}
+
+impl<N: Network, R: AsyncProgramRegistry<N>> ProgramCache<N, R> {
+    /// Initializes a new cache in front of the given registry, which holds at most `capacity` programs.
+    pub fn new(registry: R, capacity: NonZeroUsize) -> Self {
+        Self { registry, programs: Mutex::new(LruCache::new(capacity)) }
+    }
+
+    /// Returns the underlying program registry.
+    pub const fn registry(&self) -> &R {
+        &self.registry
+    }
+
+    /// Returns the maximum number of programs in the cache.
+    pub fn capacity(&self) -> usize {
+        self.programs.lock().cap().get()
+    }
+
+    /// Returns the number of programs in the cache.
+    pub fn len(&self) -> usize {
+        self.programs.lock().len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.programs.lock().is_empty()
+    }
+
+    /// Returns `true` if the program for the given import is cached, without marking it as used.
+    pub fn contains(&self, import: &Import<N>) -> bool {
+        self.programs.lock().contains(import)
+    }
+
+    /// Removes every program from the cache.
+    pub fn clear(&self) {
+        self.programs.lock().clear()
+    }
+}
+
+#[async_trait(?Send)]
+impl<N: Network, R: AsyncProgramRegistry<N>> AsyncProgramRegistry<N> for ProgramCache<N, R> {
+    /// Returns the program for the given import, from the cache if it was resolved before.
+    /// Note: Imports that the registry does not contain are not cached, as the registry may gain them later.
+    async fn get(&self, import: &Import<N>) -> Result<Option<Program<N>>> {
+        // Return the cached program, if it exists.
+        if let Some(program) = self.programs.lock().get(import) {
+            return Ok(Some(program.clone()));
+        }
+        // Otherwise, load the program from the registry, and cache it.
+        let program = self.registry.get(import).await?;
+        if let Some(program) = &program {
+            self.programs.lock().put(import.clone(), program.clone());
+        }
+        Ok(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    use core::cell::Cell;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// A registry that counts the number of programs it is asked for.
+    struct Registry(IndexMap<ProgramID<CurrentNetwork>, Program<CurrentNetwork>>, Cell<usize>);
+
+    #[async_trait(?Send)]
+    impl AsyncProgramRegistry<CurrentNetwork> for Registry {
+        async fn get(&self, import: &Import<CurrentNetwork>) -> Result<Option<Program<CurrentNetwork>>> {
+            self.1.set(self.1.get() + 1);
+            Ok(self.0.get(import.program_id()).cloned())
+        }
+    }
+
+    #[test]
+    fn test_program_cache() -> Result<()> {
+        let programs = ["foo", "bar", "baz"]
+            .iter()
+            .map(|name| Program::<CurrentNetwork>::from_str(&format!("program {name}.aleo; function noop:")))
+            .collect::<Result<Vec<_>>>()?;
+        let registry =
+            Registry(programs.iter().map(|program| (*program.id(), program.clone())).collect(), Cell::new(0));
+        let cache = ProgramCache::new(registry, NonZeroUsize::new(2).unwrap());
+        assert_eq!(cache.capacity(), 2);
+        assert!(cache.is_empty());
+
+        let [foo, bar, baz, qux] = ["foo", "bar", "baz", "qux"]
+            .map(|name| Import::<CurrentNetwork>::from_str(&format!("import {name}.aleo;")).unwrap());
+        let get = |import: &Import<CurrentNetwork>| futures::executor::block_on(cache.get(import));
+        let num_loads = || cache.registry().1.get();
+
+        // Ensure a miss loads the program from the registry, and a hit does not.
+        assert_eq!(get(&foo)?, Some(programs[0].clone()));
+        assert_eq!(num_loads(), 1);
+        assert_eq!(get(&foo)?, Some(programs[0].clone()));
+        assert_eq!(num_loads(), 1);
+
+        // Ensure the least recently used program is evicted once the cache is full.
+        assert_eq!(get(&bar)?, Some(programs[1].clone()));
+        assert_eq!(get(&baz)?, Some(programs[2].clone()));
+        assert_eq!(num_loads(), 3);
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains(&foo));
+        assert!(cache.contains(&bar) && cache.contains(&baz));
+
+        // Ensure an evicted program is loaded again.
+        assert_eq!(get(&foo)?, Some(programs[0].clone()));
+        assert_eq!(num_loads(), 4);
+        assert!(!cache.contains(&bar));
+
+        // Ensure a program that is not in the registry is not cached.
+        assert_eq!(get(&qux)?, None);
+        assert_eq!(get(&qux)?, None);
+        assert_eq!(num_loads(), 6);
+        assert!(!cache.contains(&qux));
+
+        // Ensure clearing the cache empties it.
+        cache.clear();
+        assert!(cache.is_empty());
+        Ok(())
+    }
+}
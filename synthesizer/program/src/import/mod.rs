@@ -15,11 +15,27 @@
 mod bytes;
 mod parse;
 
+#[cfg(feature = "async")]
+mod cache;
+#[cfg(feature = "async")]
+pub use cache::*;
+
+mod query;
+pub use query::*;
+
 use console::{
     network::prelude::*,
-    program::{Identifier, ProgramID},
+    program::{Field, Identifier, ProgramID},
 };
 
+#[cfg(feature = "async")]
+use crate::{AsyncProgramRegistry, Program};
+
+use indexmap::IndexMap;
+#[cfg(feature = "async")]
+use indexmap::IndexSet;
+use std::collections::BTreeSet;
+
 /// An import statement defines an imported program, and is of the form `import {name}.{network};`.
 /// If no `network`-level domain is specified, the default network is used.
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -46,6 +62,149 @@ impl<N: Network> Import<N> {
     pub const fn network(&self) -> &Identifier<N> {
         self.program_id.network()
     }
+
+    /// Returns `true` if the import is a standard program, i.e. `credits.aleo`, which every process includes.
+    pub fn is_standard(&self) -> bool {
+        self.name().to_string() == "credits" && self.network().to_string() == "aleo"
+    }
+
+    /// Returns a copy of the import with its network-level domain (NLD) lowercased.
+    /// This is never applied during parsing, and must be invoked explicitly.
+    pub fn normalized(&self) -> Result<Self> {
+        // Lowercase the network-level domain.
+        let network = Self::normalize_network(self.network())?;
+        // Construct the normalized program ID.
+        let program_id = ProgramID::try_from((*self.name(), network))?;
+        // Return the normalized import.
+        Ok(Self { program_id })
+    }
+
+    /// Returns the given imports without duplicates, in first-seen order, along with each import
+    /// that appeared more than once and the spans of all of its occurrences.
+    /// The span type is left to the caller, e.g. a byte range or a line and column.
+    pub fn dedup_with_spans<S>(imports: Vec<(Self, S)>) -> (Vec<Self>, Vec<(Self, Vec<S>)>) {
+        // Group the spans of each import, preserving the order in which the imports were first seen.
+        let mut occurrences = IndexMap::<Self, Vec<S>>::with_capacity(imports.len());
+        for (import, span) in imports {
+            occurrences.entry(import).or_default().push(span);
+        }
+        // Collect the deduplicated imports, and the imports that were duplicated.
+        let deduplicated = occurrences.keys().cloned().collect();
+        let duplicates = occurrences.into_iter().filter(|(_, spans)| spans.len() > 1).collect();
+        (deduplicated, duplicates)
+    }
+
+    /// Returns the union of the given imports, without duplicates and in sorted order.
+    /// Returns an error if the same program name is imported from different network-level domains (NLDs).
+    pub fn merge_imports(a: &[Self], b: &[Self]) -> Result<Vec<Self>> {
+        // Collect the union of the imports, without duplicates and in sorted order.
+        let merged = a.iter().chain(b).cloned().collect::<BTreeSet<_>>();
+        // Ensure each program name is imported from a single network-level domain.
+        let mut domains = IndexMap::<&Identifier<N>, &Identifier<N>>::with_capacity(merged.len());
+        for import in &merged {
+            if let Some(domain) = domains.insert(import.name(), import.network()) {
+                bail!(
+                    "Program '{}' is imported from conflicting domains '{domain}' and '{}'",
+                    import.name(),
+                    import.network()
+                );
+            }
+        }
+        Ok(merged.into_iter().collect())
+    }
+
+    /// Returns a hash of the given set of imports, which does not depend on their order or on duplicates.
+    /// This allows tools to detect a change in dependencies without comparing the full programs.
+    pub fn hash_imports(imports: &[Self]) -> Result<Field<N>> {
+        // Canonicalize the imports, by removing duplicates and sorting them.
+        let imports = imports.iter().collect::<BTreeSet<_>>();
+        // Prefix the program IDs with the number of imports.
+        let mut preimage = vec![Field::from_u64(imports.len() as u64)];
+        for import in imports {
+            preimage.extend(import.program_id.to_fields()?);
+        }
+        // Hash the canonical imports.
+        N::hash_psd2(&preimage)
+    }
+
+    /// Returns the given network-level domain (NLD) in lowercase, ensuring it remains a valid identifier.
+    fn normalize_network(network: &Identifier<N>) -> Result<Identifier<N>> {
+        let lowercase = network.to_string().to_ascii_lowercase();
+        Identifier::from_str(&lowercase)
+            .map_err(|error| anyhow!("Network-level domain '{network}' is invalid when lowercased: {error}"))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<N: Network> Import<N> {
+    /// Resolves the given imports, and transitively their imports, from the given registry.
+    /// The imports at each level of the import graph are independent, and are loaded concurrently.
+    /// Returns the resolved programs in the order in which they were loaded.
+    pub async fn resolve_async(
+        imports: &[Self],
+        registry: &impl AsyncProgramRegistry<N>,
+    ) -> Result<IndexMap<ProgramID<N>, Program<N>>> {
+        Self::resolve_pinned_async(imports, registry, &IndexMap::new()).await
+    }
+
+    /// Resolves the given imports, and transitively their imports, from the given registry.
+    /// Each program with an entry in `expected_hashes` must hash to the pinned value, or resolution fails.
+    /// Returns the resolved programs in the order in which they were loaded.
+    pub async fn resolve_pinned_async(
+        imports: &[Self],
+        registry: &impl AsyncProgramRegistry<N>,
+        expected_hashes: &IndexMap<ProgramID<N>, Field<N>>,
+    ) -> Result<IndexMap<ProgramID<N>, Program<N>>> {
+        let mut programs = IndexMap::<ProgramID<N>, Program<N>>::new();
+        // Initialize the imports to load, without duplicates.
+        let mut pending = imports.iter().cloned().collect::<IndexSet<_>>();
+
+        while !pending.is_empty() {
+            // Load the pending imports concurrently.
+            let loaded = futures::future::try_join_all(pending.iter().map(|import| {
+                let expected_hash = expected_hashes.get(import.program_id()).copied();
+                import.load_async(registry, expected_hash)
+            }))
+            .await?;
+
+            let mut next = IndexSet::new();
+            for (import, program) in pending.into_iter().zip_eq(loaded) {
+                // Queue the imports of the program.
+                next.extend(program.imports().values().cloned());
+                programs.insert(import.program_id, program);
+            }
+            // Retain the imports that have not been loaded yet.
+            pending = next.into_iter().filter(|import| !programs.contains_key(import.program_id())).collect();
+        }
+        Ok(programs)
+    }
+
+    /// Loads the imported program from the given registry, ensuring the registry returned the imported program.
+    /// If `expected_hash` is given, the program must also hash to it, which pins the import to an exact program.
+    pub async fn load_async(
+        &self,
+        registry: &impl AsyncProgramRegistry<N>,
+        expected_hash: Option<Field<N>>,
+    ) -> Result<Program<N>> {
+        // Ensure the registry returned the imported program.
+        let program = registry.get(self).await?.ok_or_else(|| anyhow!("Import '{}' was not found", self.program_id))?;
+        ensure!(
+            program.id() == self.program_id(),
+            "Expected the program '{}', but the registry returned '{}'",
+            self.program_id,
+            program.id()
+        );
+        // Ensure the program matches the pinned hash, if one is given.
+        if let Some(expected_hash) = expected_hash {
+            let hash = program.hash()?;
+            ensure!(
+                hash == expected_hash,
+                "Program '{}' has the hash '{hash}', but the import is pinned to '{expected_hash}'",
+                self.program_id
+            );
+        }
+        Ok(program)
+    }
 }
 
 impl<N: Network> TypeName for Import<N> {
@@ -56,6 +215,20 @@ impl<N: Network> TypeName for Import<N> {
     }
 }
 
+/// The policy of a network on the imports that a program may declare.
+pub trait ImportPolicy: Network {
+    /// Returns `true` if a program may declare the given import.
+    fn is_import_permitted(import: &Import<Self>) -> bool;
+}
+
+impl<N: Network> ImportPolicy for N {
+    /// Returns `true` if the imported program name is not reserved by the network.
+    fn is_import_permitted(import: &Import<N>) -> bool {
+        let name = import.name().to_string();
+        !N::RESERVED_IMPORT_NAMES.contains(&name.as_str())
+    }
+}
+
 impl<N: Network> Ord for Import<N> {
     /// Ordering is determined by the NLD first, then the program name second.
     fn cmp(&self, other: &Self) -> Ordering {
@@ -113,4 +286,188 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_import_is_standard() -> Result<()> {
+        assert!(Import::<CurrentNetwork>::from_str("import credits.aleo;")?.is_standard());
+        assert!(!Import::<CurrentNetwork>::from_str("import credit.aleo;")?.is_standard());
+        assert!(!Import::<CurrentNetwork>::from_str("import foo.aleo;")?.is_standard());
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_normalized() -> Result<()> {
+        // Ensure a lowercase import is unchanged.
+        let import = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        assert_eq!(import, import.normalized()?);
+
+        // Ensure an uppercase domain is lowercased.
+        let network = Identifier::<CurrentNetwork>::from_str("ALEO")?;
+        assert_eq!(Import::normalize_network(&network)?, Identifier::from_str("aleo")?);
+        let network = Identifier::<CurrentNetwork>::from_str("AlEo")?;
+        assert_eq!(Import::normalize_network(&network)?, Identifier::from_str("aleo")?);
+
+        // Ensure a domain that becomes a reserved literal type when lowercased is rejected.
+        let network = Identifier::<CurrentNetwork>::from_str("U8")?;
+        assert!(Import::<CurrentNetwork>::normalize_network(&network).is_err());
+        let network = Identifier::<CurrentNetwork>::from_str("FIELD")?;
+        assert!(Import::<CurrentNetwork>::normalize_network(&network).is_err());
+
+        // Ensure normalization is not applied automatically during parsing.
+        assert!(Import::<CurrentNetwork>::from_str("import foo.ALEO;").is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_import_resolve_async() -> Result<()> {
+        use crate::{AsyncProgramRegistry, Program};
+
+        struct Registry(IndexMap<ProgramID<CurrentNetwork>, Program<CurrentNetwork>>);
+
+        #[async_trait(?Send)]
+        impl AsyncProgramRegistry<CurrentNetwork> for Registry {
+            async fn get(&self, import: &Import<CurrentNetwork>) -> Result<Option<Program<CurrentNetwork>>> {
+                Ok(self.0.get(import.program_id()).cloned())
+            }
+        }
+
+        // Initialize a registry, where `foo.aleo` imports `bar.aleo` and `baz.aleo`, and `bar.aleo` imports `baz.aleo`.
+        let programs = [
+            "import bar.aleo; import baz.aleo; program foo.aleo; function noop: input r0 as u8.private;",
+            "import baz.aleo; program bar.aleo; function noop: input r0 as u8.private;",
+            "program baz.aleo; function noop: input r0 as u8.private;",
+        ]
+        .iter()
+        .map(|program| Program::<CurrentNetwork>::from_str(program))
+        .collect::<Result<Vec<_>>>()?;
+        let registry = Registry(programs.iter().map(|program| (*program.id(), program.clone())).collect());
+
+        // Ensure the imports are resolved transitively, with each program loaded once.
+        let foo = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        let resolved = futures::executor::block_on(Import::resolve_async(&[foo.clone(), foo], &registry))?;
+        assert_eq!(resolved.keys().map(ToString::to_string).collect::<Vec<_>>(), ["foo.aleo", "bar.aleo", "baz.aleo"]);
+        assert_eq!(resolved.values().cloned().collect::<Vec<_>>(), programs);
+
+        // Ensure an import that is not in the registry is rejected.
+        let missing = Import::<CurrentNetwork>::from_str("import qux.aleo;")?;
+        assert!(futures::executor::block_on(Import::resolve_async(&[missing], &registry)).is_err());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_import_resolve_pinned_async() -> Result<()> {
+        use crate::{AsyncProgramRegistry, Program};
+
+        struct Registry(IndexMap<ProgramID<CurrentNetwork>, Program<CurrentNetwork>>);
+
+        #[async_trait(?Send)]
+        impl AsyncProgramRegistry<CurrentNetwork> for Registry {
+            async fn get(&self, import: &Import<CurrentNetwork>) -> Result<Option<Program<CurrentNetwork>>> {
+                Ok(self.0.get(import.program_id()).cloned())
+            }
+        }
+
+        // Initialize a registry, where `foo.aleo` imports `bar.aleo`.
+        let foo = Program::<CurrentNetwork>::from_str(
+            "import bar.aleo; program foo.aleo; function noop: input r0 as u8.private;",
+        )?;
+        let bar = Program::<CurrentNetwork>::from_str("program bar.aleo; function noop: input r0 as u8.private;")?;
+        let registry = Registry(IndexMap::from([(*foo.id(), foo.clone()), (*bar.id(), bar.clone())]));
+
+        // Ensure the imports are resolved when the pinned hashes match.
+        let import = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        let imports = [import.clone()];
+        let pins = IndexMap::from([(*foo.id(), foo.hash()?), (*bar.id(), bar.hash()?)]);
+        let resolved = futures::executor::block_on(Import::resolve_pinned_async(&imports, &registry, &pins))?;
+        assert_eq!(resolved.values().cloned().collect::<Vec<_>>(), [foo.clone(), bar.clone()]);
+        assert_eq!(futures::executor::block_on(import.load_async(&registry, Some(foo.hash()?)))?, foo);
+
+        // Ensure a tampered transitive import is rejected.
+        let tampered = "program bar.aleo; function noop: input r0 as u16.private;";
+        let tampered = Program::<CurrentNetwork>::from_str(tampered)?;
+        assert_ne!(tampered.hash()?, bar.hash()?);
+        let tampered_registry = Registry(IndexMap::from([(*foo.id(), foo.clone()), (*bar.id(), tampered)]));
+        let result = Import::resolve_pinned_async(&imports, &tampered_registry, &pins);
+        assert!(futures::executor::block_on(result).is_err());
+
+        // Ensure the tampered import is accepted when it is not pinned.
+        let pins = IndexMap::from([(*foo.id(), foo.hash()?)]);
+        let result = Import::resolve_pinned_async(&imports, &tampered_registry, &pins);
+        assert!(futures::executor::block_on(result).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_dedup_with_spans() -> Result<()> {
+        let foo = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        let bar = Import::<CurrentNetwork>::from_str("import bar.aleo;")?;
+        let baz = Import::<CurrentNetwork>::from_str("import baz.aleo;")?;
+
+        // Ensure imports without duplicates are returned unchanged.
+        let (imports, duplicates) = Import::dedup_with_spans(vec![(foo.clone(), 0..16), (bar.clone(), 17..33)]);
+        assert_eq!(imports, vec![foo.clone(), bar.clone()]);
+        assert!(duplicates.is_empty());
+
+        // Ensure duplicates are removed in first-seen order, and report the spans of every occurrence.
+        let (imports, duplicates) = Import::dedup_with_spans(vec![
+            (bar.clone(), 0..16),
+            (foo.clone(), 17..33),
+            (bar.clone(), 34..50),
+            (baz.clone(), 51..67),
+            (bar.clone(), 68..84),
+            (foo.clone(), 85..101),
+        ]);
+        assert_eq!(imports, vec![bar.clone(), foo.clone(), baz]);
+        assert_eq!(duplicates, vec![(bar, vec![0..16, 34..50, 68..84]), (foo, vec![17..33, 85..101])]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_merge_imports() -> Result<()> {
+        let foo = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        let bar = Import::<CurrentNetwork>::from_str("import bar.aleo;")?;
+        let baz = Import::<CurrentNetwork>::from_str("import baz.aleo;")?;
+
+        // Ensure the imports are merged without duplicates, in sorted order.
+        let merged = Import::merge_imports(&[foo.clone(), bar.clone()], &[baz.clone(), foo.clone(), foo.clone()])?;
+        assert_eq!(merged, vec![bar.clone(), baz.clone(), foo.clone()]);
+        assert_eq!(Import::merge_imports(&[], &[foo.clone()])?, vec![foo.clone()]);
+        assert!(Import::<CurrentNetwork>::merge_imports(&[], &[])?.is_empty());
+
+        // Ensure the merge does not depend on the order of its arguments.
+        assert_eq!(Import::merge_imports(&[baz, foo.clone()], &[bar, foo])?, merged);
+
+        // Note: A name cannot currently be imported from another domain, as a program ID must use the `aleo` domain.
+        assert!(Import::<CurrentNetwork>::from_str("import foo.other;").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_hash_imports() -> Result<()> {
+        let foo = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        let bar = Import::<CurrentNetwork>::from_str("import bar.aleo;")?;
+        let baz = Import::<CurrentNetwork>::from_str("import baz.aleo;")?;
+
+        // Ensure the hash does not depend on the order of the imports.
+        let expected = Import::hash_imports(&[foo.clone(), bar.clone(), baz.clone()])?;
+        assert_eq!(Import::hash_imports(&[baz.clone(), foo.clone(), bar.clone()])?, expected);
+        assert_eq!(Import::hash_imports(&[bar.clone(), baz.clone(), foo.clone()])?, expected);
+
+        // Ensure the hash does not depend on duplicates.
+        assert_eq!(Import::hash_imports(&[foo.clone(), bar.clone(), foo.clone(), baz.clone(), bar.clone()])?, expected);
+
+        // Ensure a different set of imports has a different hash.
+        assert_ne!(Import::hash_imports(&[foo, bar])?, expected);
+        assert_ne!(Import::hash_imports(&[baz])?, expected);
+        assert_ne!(Import::hash_imports(&[])?, expected);
+
+        Ok(())
+    }
 }
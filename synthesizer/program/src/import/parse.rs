@@ -36,6 +36,45 @@ impl<N: Network> Parser for Import<N> {
     }
 }
 
+impl<N: Network> Import<N> {
+    /// Parses a string into an import statement, which may omit its network-level domain (NLD) as in `import {name};`.
+    /// A domain-less import is resolved to the given `base_domain` of the importer, while a fully-qualified import
+    /// is returned unchanged. Programs must always qualify their imports, so this is not accepted by [`Self::parse`].
+    pub fn parse_relative<'a>(string: &'a str, base_domain: &Identifier<N>) -> ParserResult<'a, Self> {
+        // Parse a fully-qualified import statement.
+        if let Ok((string, import)) = Self::parse(string) {
+            return Ok((string, import));
+        }
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the import keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the program name from the string, and resolve it to the base domain.
+        let (string, id) = map_res(Identifier::parse, |name| ProgramID::try_from((name, *base_domain)))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the import statement.
+        Ok((string, Self { program_id: id }))
+    }
+
+    /// Parses a string into an import statement, resolving a missing network-level domain (NLD) to `base_domain`.
+    pub fn from_str_relative(string: &str, base_domain: &Identifier<N>) -> Result<Self> {
+        match Self::parse_relative(string, base_domain) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
 impl<N: Network> FromStr for Import<N> {
     type Err = Error;
 
@@ -63,8 +102,12 @@ impl<N: Network> Debug for Import<N> {
 
 impl<N: Network> Display for Import<N> {
     /// Prints the import statement as a string.
+    /// The alternate form (`{:#}`) prints only the imported program ID, e.g. `foo.aleo`.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{type_} {id};", type_ = Self::type_name(), id = self.program_id)
+        match f.alternate() {
+            true => write!(f, "{id}", id = self.program_id),
+            false => write!(f, "{type_} {id};", type_ = Self::type_name(), id = self.program_id),
+        }
     }
 }
 
@@ -88,6 +131,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_import_parse_relative() -> Result<()> {
+        let base_domain = Identifier::<CurrentNetwork>::from_str("aleo")?;
+
+        // Ensure a domain-less import is resolved to the base domain.
+        let import = Import::<CurrentNetwork>::from_str_relative("import foo;", &base_domain)?;
+        assert_eq!(import, Import::from_str("import foo.aleo;")?);
+
+        // Ensure a fully-qualified import is returned unchanged.
+        let import = Import::<CurrentNetwork>::from_str_relative("import bar.aleo;", &base_domain)?;
+        assert_eq!(import, Import::from_str("import bar.aleo;")?);
+
+        // Ensure a domain-less import is rejected by the program parser.
+        assert!(Import::<CurrentNetwork>::from_str("import foo;").is_err());
+        // Ensure an invalid base domain is rejected.
+        let invalid_domain = Identifier::<CurrentNetwork>::from_str("other")?;
+        assert!(Import::<CurrentNetwork>::from_str_relative("import foo;", &invalid_domain).is_err());
+        // Ensure a malformed import is rejected.
+        assert!(Import::<CurrentNetwork>::from_str_relative("import foo", &base_domain).is_err());
+        assert!(Import::<CurrentNetwork>::from_str_relative("import foo.other;", &base_domain).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_import_display() -> Result<()> {
         let import = Import::<CurrentNetwork>::from_str("import bar.aleo;")?;
@@ -96,6 +163,10 @@ mod tests {
         let import = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
         assert_eq!("import foo.aleo;", import.to_string());
 
+        // Ensure the alternate form prints only the program ID.
+        assert_eq!("foo.aleo", format!("{import:#}"));
+        assert_eq!("import foo.aleo;", format!("{import}"));
+
         Ok(())
     }
 }
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The network-level domain (NLD) that an import query matches.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainQuery<N: Network> {
+    /// Matches any network-level domain.
+    Any,
+    /// Matches only the given network-level domain.
+    Exact(Identifier<N>),
+}
+
+/// An import query matches imports by program name, and by either a concrete or any network-level domain (NLD).
+/// Unlike an `Import`, which always refers to one concrete program, a query may match several programs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ImportQuery<N: Network> {
+    /// The program name to match.
+    name: Identifier<N>,
+    /// The network-level domain to match.
+    domain: DomainQuery<N>,
+}
+
+impl<N: Network> ImportQuery<N> {
+    /// Initializes a query for the given program name, in any network-level domain.
+    pub const fn any(name: Identifier<N>) -> Self {
+        Self { name, domain: DomainQuery::Any }
+    }
+
+    /// Initializes a query for the given program name, in the given network-level domain.
+    pub const fn exact(name: Identifier<N>, network: Identifier<N>) -> Self {
+        Self { name, domain: DomainQuery::Exact(network) }
+    }
+
+    /// Returns the program name to match.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the network-level domain to match.
+    pub const fn domain(&self) -> &DomainQuery<N> {
+        &self.domain
+    }
+
+    /// Returns `true` if the given program name and network-level domain match the query.
+    pub fn matches(&self, name: &Identifier<N>, network: &Identifier<N>) -> bool {
+        self.name == *name
+            && match &self.domain {
+                DomainQuery::Any => true,
+                DomainQuery::Exact(domain) => domain == network,
+            }
+    }
+
+    /// Returns `true` if the given import matches the query.
+    pub fn matches_import(&self, import: &Import<N>) -> bool {
+        self.matches(import.name(), import.network())
+    }
+}
+
+impl<N: Network> From<&Import<N>> for ImportQuery<N> {
+    /// Returns a query that matches exactly the given import.
+    fn from(import: &Import<N>) -> Self {
+        Self::exact(*import.name(), *import.network())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_import_query_matches() -> Result<()> {
+        let foo = Identifier::<CurrentNetwork>::from_str("foo")?;
+        let bar = Identifier::<CurrentNetwork>::from_str("bar")?;
+        let aleo = Identifier::<CurrentNetwork>::from_str("aleo")?;
+        let test = Identifier::<CurrentNetwork>::from_str("test")?;
+
+        // Ensure a query for any domain matches the name in every domain.
+        let query = ImportQuery::any(foo);
+        assert!(query.matches(&foo, &aleo));
+        assert!(query.matches(&foo, &test));
+        assert!(!query.matches(&bar, &aleo));
+
+        // Ensure a query for a concrete domain matches only that domain.
+        let query = ImportQuery::exact(foo, aleo);
+        assert!(query.matches(&foo, &aleo));
+        assert!(!query.matches(&foo, &test));
+        assert!(!query.matches(&bar, &aleo));
+
+        // Ensure a query built from an import matches exactly that import.
+        let import = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        assert_eq!(ImportQuery::from(&import), query);
+        assert!(query.matches_import(&import));
+        assert!(!ImportQuery::any(bar).matches_import(&import));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Imports<N> {
+    /// Reads the imports from a buffer.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        // Read the imports.
+        let imports = Import::read_le_vec(reader)?;
+        // Ensure the imports are in canonical order and without duplicates, so that the encoding is unique.
+        if imports.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(error("Imports must be sorted and without duplicates"));
+        }
+        // Return the imports.
+        Ok(imports.into())
+    }
+}
+
+impl<N: Network> ToBytes for Imports<N> {
+    /// Writes the imports to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        Import::write_le_vec(&self.iter().cloned().collect::<Vec<_>>(), writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_imports_bytes() -> Result<()> {
+        let expected = Imports::<CurrentNetwork>::from_str("import foo.aleo;\nimport bar.aleo;")?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Imports::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_imports_bytes_are_canonical() -> Result<()> {
+        let foo = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        let bar = Import::<CurrentNetwork>::from_str("import bar.aleo;")?;
+
+        // Ensure unsorted or duplicated imports are rejected.
+        for imports in [vec![foo.clone(), bar.clone()], vec![bar.clone(), bar]] {
+            let mut bytes = Vec::new();
+            Import::write_le_vec(&imports, &mut bytes)?;
+            assert!(Imports::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use crate::Import;
+use console::network::prelude::*;
+
+use std::collections::BTreeSet;
+
+/// A set of import statements, which holds each import at most once and in canonical order.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Imports<N: Network> {
+    /// The imports, ordered by their program IDs.
+    imports: BTreeSet<Import<N>>,
+}
+
+impl<N: Network> Imports<N> {
+    /// Initializes an empty set of imports.
+    pub const fn new() -> Self {
+        Self { imports: BTreeSet::new() }
+    }
+
+    /// Adds the given import, returning `false` if it was already present.
+    pub fn insert(&mut self, import: Import<N>) -> bool {
+        self.imports.insert(import)
+    }
+
+    /// Returns `true` if the given import is present.
+    pub fn contains(&self, import: &Import<N>) -> bool {
+        self.imports.contains(import)
+    }
+
+    /// Returns the imports in canonical order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Import<N>> {
+        self.imports.iter()
+    }
+
+    /// Returns the number of imports.
+    pub fn len(&self) -> usize {
+        self.imports.len()
+    }
+
+    /// Returns `true` if there are no imports.
+    pub fn is_empty(&self) -> bool {
+        self.imports.is_empty()
+    }
+}
+
+impl<N: Network> Default for Imports<N> {
+    /// Initializes an empty set of imports.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> FromIterator<Import<N>> for Imports<N> {
+    /// Collects the given imports, discarding duplicates.
+    fn from_iter<I: IntoIterator<Item = Import<N>>>(iter: I) -> Self {
+        Self { imports: iter.into_iter().collect() }
+    }
+}
+
+impl<N: Network> From<Vec<Import<N>>> for Imports<N> {
+    /// Converts the given imports into a set, discarding duplicates.
+    fn from(imports: Vec<Import<N>>) -> Self {
+        imports.into_iter().collect()
+    }
+}
+
+impl<N: Network> From<Imports<N>> for Vec<Import<N>> {
+    /// Converts the set into a list of imports, in canonical order.
+    fn from(imports: Imports<N>) -> Self {
+        imports.imports.into_iter().collect()
+    }
+}
+
+impl<N: Network> IntoIterator for Imports<N> {
+    type IntoIter = std::collections::btree_set::IntoIter<Import<N>>;
+    type Item = Import<N>;
+
+    /// Returns the imports in canonical order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.imports.into_iter()
+    }
+}
+
+impl<'a, N: Network> IntoIterator for &'a Imports<N> {
+    type IntoIter = std::collections::btree_set::Iter<'a, Import<N>>;
+    type Item = &'a Import<N>;
+
+    /// Returns the imports in canonical order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.imports.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_imports_set_semantics() -> Result<()> {
+        let foo = Import::<CurrentNetwork>::from_str("import foo.aleo;")?;
+        let bar = Import::<CurrentNetwork>::from_str("import bar.aleo;")?;
+
+        // Ensure each import is held once.
+        let mut imports = Imports::new();
+        assert!(imports.insert(foo.clone()));
+        assert!(imports.insert(bar.clone()));
+        assert!(!imports.insert(foo.clone()));
+        assert_eq!(imports.len(), 2);
+        assert!(imports.contains(&foo));
+        assert!(!imports.contains(&Import::from_str("import baz.aleo;")?));
+
+        // Ensure the imports are in canonical order, regardless of the order they were added in.
+        assert_eq!(imports.iter().collect::<Vec<_>>(), vec![&bar, &foo]);
+        let converted = Imports::from(vec![foo.clone(), bar.clone(), foo.clone()]);
+        assert_eq!(converted, imports);
+        assert_eq!(Vec::from(converted), vec![bar, foo]);
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for Imports<N> {
+    /// Parses a string into a set of import statements.
+    /// Returns an error if the same program is imported more than once.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the imports from the string.
+        let (string, imports) = many0(Import::parse)(string)?;
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+
+        // Ensure there are no duplicate imports.
+        map_res(take(0usize), move |_| {
+            let num_imports = imports.len();
+            let imports = Self::from(imports.clone());
+            match imports.len() == num_imports {
+                true => Ok(imports),
+                false => Err(error("Duplicate imports are not allowed")),
+            }
+        })(string)
+    }
+}
+
+impl<N: Network> FromStr for Imports<N> {
+    type Err = Error;
+
+    /// Parses a string into a set of import statements.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Imports<N> {
+    /// Prints the imports as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Imports<N> {
+    /// Prints the import statements in canonical order, one per line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.iter().join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_imports_parse() -> Result<()> {
+        let imports = Imports::<CurrentNetwork>::parse("import foo.aleo;\nimport bar.aleo;").unwrap().1;
+        assert_eq!(imports.len(), 2);
+        assert!(imports.contains(&Import::from_str("import foo.aleo;")?));
+        assert!(imports.contains(&Import::from_str("import bar.aleo;")?));
+
+        // Ensure an empty string parses into no imports.
+        assert!(Imports::<CurrentNetwork>::from_str("")?.is_empty());
+        // Ensure duplicate imports are rejected.
+        assert!(Imports::<CurrentNetwork>::from_str("import foo.aleo;\nimport foo.aleo;").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_imports_display() -> Result<()> {
+        // Ensure the imports are printed in canonical order.
+        let imports = Imports::<CurrentNetwork>::from_str("import foo.aleo;\nimport bar.aleo;")?;
+        assert_eq!("import bar.aleo;\nimport foo.aleo;", imports.to_string());
+        assert_eq!(imports, Imports::from_str(&imports.to_string())?);
+        Ok(())
+    }
+}
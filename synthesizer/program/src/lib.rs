@@ -16,6 +16,10 @@
 #![allow(clippy::too_many_arguments)]
 #![warn(clippy::cast_possible_truncation)]
 
+#[cfg(feature = "async")]
+#[macro_use]
+extern crate async_trait;
+
 pub type Program<N> = crate::ProgramCore<N, Instruction<N>, Command<N>>;
 pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
@@ -33,6 +37,9 @@ pub use function::*;
 mod import;
 pub use import::*;
 
+mod imports;
+pub use imports::*;
+
 pub mod logic;
 pub use logic::*;
 
@@ -79,15 +86,26 @@ use console::{
         Sanitizer,
         Serialize,
         Serializer,
+        ToBits,
         ToBytes,
         ToBytesSerializer,
         TypeName,
         Write,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{
+        Field,
+        FinalizeType,
+        Identifier,
+        PlaintextType,
+        ProgramID,
+        RecordType,
+        RegisterType,
+        StructType,
+        ValueType,
+    },
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 enum ProgramDefinition {
@@ -183,11 +201,84 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.functions
     }
 
+    /// Returns the hash of the program, computed over its byte representation.
+    pub fn hash(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
     }
 
+    /// Returns the imports that match the given query, in the order in which they were declared.
+    pub fn matching_imports<'a>(&'a self, query: &'a ImportQuery<N>) -> impl Iterator<Item = &'a Import<N>> + 'a {
+        self.imports.values().filter(move |import| query.matches_import(import))
+    }
+
+    /// Returns the imports that must be fetched as external dependencies, in the order in which they were declared.
+    /// This excludes an import of the program itself, and imports of standard programs.
+    pub fn external_imports(&self) -> impl Iterator<Item = &Import<N>> {
+        self.imports.values().filter(move |import| import.program_id() != &self.id && !import.is_standard())
+    }
+
+    /// Ensures every import is permitted by the network, returning an error listing the disallowed imports.
+    pub fn check_imports_permitted(&self) -> Result<()> {
+        self.check_imports_permitted_by(N::is_import_permitted)
+    }
+
+    /// Ensures every import is permitted by the given policy, returning an error listing the disallowed imports.
+    pub fn check_imports_permitted_by(&self, is_import_permitted: impl Fn(&Import<N>) -> bool) -> Result<()> {
+        let disallowed = self
+            .imports
+            .values()
+            .filter(|import| !is_import_permitted(import))
+            .map(|import| import.program_id().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        ensure!(disallowed.is_empty(), "Program '{}' has disallowed imports: {disallowed}", self.id);
+        Ok(())
+    }
+
+    /// Returns the declared imports that are never referenced in the program body.
+    pub fn unused_imports(&self) -> Vec<Import<N>> {
+        // Initialize a set of the referenced program IDs.
+        let mut referenced = IndexSet::new();
+
+        // Collect the program IDs referenced by the closures.
+        for closure in self.closures.values() {
+            let inputs = closure.inputs().iter().map(|input| input.register_type());
+            let outputs = closure.outputs().iter().map(|output| output.register_type());
+            referenced.extend(inputs.chain(outputs).filter_map(|register_type| match register_type {
+                RegisterType::ExternalRecord(locator) | RegisterType::Future(locator) => Some(*locator.program_id()),
+                RegisterType::Plaintext(..) | RegisterType::Record(..) => None,
+            }));
+            referenced.extend(closure.instructions().iter().flat_map(|instruction| instruction.referenced_programs()));
+        }
+
+        // Collect the program IDs referenced by the functions and their finalize logic.
+        for function in self.functions.values() {
+            let types = function.input_types().into_iter().chain(function.output_types());
+            referenced.extend(types.filter_map(|value_type| match value_type {
+                ValueType::ExternalRecord(locator) | ValueType::Future(locator) => Some(*locator.program_id()),
+                ValueType::Constant(..) | ValueType::Public(..) | ValueType::Private(..) => None,
+                ValueType::Record(..) => None,
+            }));
+            referenced.extend(function.instructions().iter().flat_map(|instruction| instruction.referenced_programs()));
+
+            if let Some(finalize) = function.finalize_logic() {
+                referenced.extend(finalize.input_types().into_iter().filter_map(|finalize_type| match finalize_type {
+                    FinalizeType::Future(locator) => Some(*locator.program_id()),
+                    FinalizeType::Plaintext(..) => None,
+                }));
+                referenced.extend(finalize.commands().iter().flat_map(|command| command.referenced_programs()));
+            }
+        }
+
+        // Return the imports that are not referenced.
+        self.imports.values().filter(|import| !referenced.contains(import.program_id())).cloned().collect()
+    }
+
     /// Returns `true` if the program contains a mapping with the given name.
     pub fn contains_mapping(&self, name: &Identifier<N>) -> bool {
         self.mappings.contains_key(name)
@@ -296,7 +387,12 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         let import_name = *import.name();
 
         // Ensure that the number of imports is within the allowed range.
-        ensure!(self.imports.len() < N::MAX_IMPORTS, "Program exceeds the maximum number of imports");
+        ensure!(
+            self.imports.len() < N::MAX_IMPORTS,
+            "Program exceeds the maximum number of imports ({} imports, limit {})",
+            self.imports.len() + 1,
+            N::MAX_IMPORTS
+        );
 
         // Ensure the import name is new.
         ensure!(self.is_unique_name(&import_name), "'{import_name}' is already in use.");
@@ -762,6 +858,151 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_imports_permitted() -> Result<()> {
+        let source = r"
+import eth.aleo;
+import usdc.aleo;
+import weth.aleo;
+import wbtc.aleo;
+
+program swap.aleo;
+
+function noop:
+    input r0 as u8.private;
+    ";
+
+        // Ensure the default network policy permits all imports.
+        let program = Program::<CurrentNetwork>::from_str(source)?;
+        assert!(CurrentNetwork::RESERVED_IMPORT_NAMES.is_empty());
+        assert!(program.imports().values().all(CurrentNetwork::is_import_permitted));
+        program.check_imports_permitted()?;
+
+        // Initialize a policy that forbids importing programs whose name starts with `w`.
+        let is_import_permitted = |import: &Import<CurrentNetwork>| !import.name().to_string().starts_with('w');
+
+        // Ensure the policy rejects the forbidden imports, listing every one of them.
+        let error = program.check_imports_permitted_by(is_import_permitted).unwrap_err();
+        assert_eq!(error.to_string(), "Program 'swap.aleo' has disallowed imports: weth.aleo, wbtc.aleo");
+
+        // Ensure the policy permits a program without forbidden imports.
+        let program = Program::<CurrentNetwork>::from_str(&source.replace("import weth.aleo;\nimport wbtc.aleo;\n", ""))?;
+        assert_eq!(program.imports().len(), 2);
+        program.check_imports_permitted_by(is_import_permitted)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_unused_imports() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import eth.aleo;
+import usdc.aleo;
+import weth.aleo;
+import dai.aleo;
+
+program swap.aleo;
+
+function swap:
+    input r0 as eth.aleo/eth.record;
+    call usdc.aleo/transfer r0.owner r0.amount into r1;
+    async swap r1 into r2;
+    output r1 as u64.private;
+    output r2 as swap.aleo/swap.future;
+
+finalize swap:
+    input r0 as u64.public;
+    get.or_use dai.aleo/balances[r0] 0u64 into r1;
+    ",
+        )?;
+
+        // Ensure only the unreferenced import is reported.
+        let unused = program.unused_imports();
+        assert_eq!(unused.len(), 1);
+        assert_eq!(unused[0].program_id(), &ProgramID::from_str("weth.aleo")?);
+
+        // Ensure a program without imports reports none.
+        let program = Program::<CurrentNetwork>::from_str("program noop.aleo; function noop: input r0 as u8.private;")?;
+        assert!(program.unused_imports().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_matching_imports() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import eth.aleo;
+import usdc.aleo;
+
+program swap.aleo;
+
+function noop:
+    input r0 as u8.private;
+    ",
+        )?;
+        let eth = Identifier::from_str("eth")?;
+
+        // Ensure a query for any domain and a query for the concrete domain both find the import.
+        let expected = vec![Import::from_str("import eth.aleo;")?];
+        assert_eq!(program.matching_imports(&ImportQuery::any(eth)).cloned().collect::<Vec<_>>(), expected);
+        let query = ImportQuery::exact(eth, Identifier::from_str("aleo")?);
+        assert_eq!(program.matching_imports(&query).cloned().collect::<Vec<_>>(), expected);
+
+        // Ensure a query for another domain, or another name, finds nothing.
+        let query = ImportQuery::exact(eth, Identifier::from_str("test")?);
+        assert_eq!(program.matching_imports(&query).count(), 0);
+        assert_eq!(program.matching_imports(&ImportQuery::any(Identifier::from_str("weth")?)).count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_external_imports() -> Result<()> {
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::from_str(
+            r"
+import credits.aleo;
+import eth.aleo;
+import usdc.aleo;
+
+program swap.aleo;
+
+function noop:
+    input r0 as u8.private;
+    ",
+        )?;
+
+        // Ensure the standard import is excluded.
+        let expected = vec![Import::from_str("import eth.aleo;")?, Import::from_str("import usdc.aleo;")?];
+        assert_eq!(program.external_imports().cloned().collect::<Vec<_>>(), expected);
+
+        // Ensure an import of the program itself is excluded.
+        program.add_import(Import::from_str("import swap.aleo;")?)?;
+        assert_eq!(program.imports().len(), 4);
+        assert_eq!(program.external_imports().cloned().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_max_imports() -> Result<()> {
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("swap.aleo")?)?;
+
+        // Ensure up to `MAX_IMPORTS` imports are accepted.
+        for i in 0..CurrentNetwork::MAX_IMPORTS {
+            program.add_import(Import::from_str(&format!("import test{i}.aleo;"))?)?;
+        }
+        assert_eq!(program.imports().len(), CurrentNetwork::MAX_IMPORTS);
+
+        // Ensure one more import is rejected, with an error naming the count and the limit.
+        let error = program.add_import(Import::from_str("import overflow.aleo;")?).unwrap_err().to_string();
+        let limit = CurrentNetwork::MAX_IMPORTS;
+        assert!(error.contains(&format!("({} imports, limit {limit})", limit + 1)), "{error}");
+        assert_eq!(program.imports().len(), limit);
+        Ok(())
+    }
+
     #[test]
     fn test_program_import() -> Result<()> {
         // Initialize a new program.
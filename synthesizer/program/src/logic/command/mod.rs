@@ -49,14 +49,16 @@ use crate::{
         StackMatches,
         StackProgram,
     },
+    CallOperator,
     CastType,
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Operand,
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Register},
+    program::{Identifier, ProgramID, Register},
 };
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -105,6 +107,36 @@ impl<N: Network> CommandTrait<N> for Command<N> {
         }
     }
 
+    /// Returns the IDs of the programs referenced by the command.
+    #[inline]
+    fn referenced_programs(&self) -> Vec<ProgramID<N>> {
+        // Retrieve the operands and the mapping of the command.
+        let (operands, mapping) = match self {
+            Command::Instruction(instruction) => return instruction.referenced_programs(),
+            Command::Await(_) | Command::Position(_) => (vec![], None),
+            Command::Contains(contains) => (contains.operands(), Some(contains.mapping())),
+            Command::Get(get) => (get.operands(), Some(get.mapping())),
+            Command::GetOrUse(get_or_use) => (get_or_use.operands(), Some(get_or_use.mapping())),
+            Command::RandChaCha(rand_chacha) => (rand_chacha.operands(), None),
+            Command::Remove(remove) => (remove.operands(), None),
+            Command::Set(set) => (set.operands(), None),
+            Command::BranchEq(branch_eq) => (vec![branch_eq.first().clone(), branch_eq.second().clone()], None),
+            Command::BranchNeq(branch_neq) => (vec![branch_neq.first().clone(), branch_neq.second().clone()], None),
+        };
+        // Collect the program IDs used as operands, and the program ID of an external mapping.
+        operands
+            .into_iter()
+            .filter_map(|operand| match operand {
+                Operand::ProgramID(program_id) => Some(program_id),
+                _ => None,
+            })
+            .chain(match mapping {
+                Some(CallOperator::Locator(locator)) => Some(*locator.program_id()),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Returns the branch target, if the command is a branch command.
     /// Otherwise, returns `None`.
     #[inline]
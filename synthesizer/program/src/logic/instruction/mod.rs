@@ -60,7 +60,7 @@ use console::{
         ToBytes,
         Write,
     },
-    program::{Register, RegisterType},
+    program::{ProgramID, Register, RegisterType},
 };
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -379,6 +379,40 @@ impl<N: Network> InstructionTrait<N> for Instruction<N> {
         instruction!(self, |instruction| instruction.destinations())
     }
 
+    /// Returns the IDs of the programs referenced by the instruction.
+    #[inline]
+    fn referenced_programs(&self) -> Vec<ProgramID<N>> {
+        // Collect the program IDs used as operands.
+        let mut programs = self
+            .operands()
+            .iter()
+            .filter_map(|operand| match operand {
+                Operand::ProgramID(program_id) => Some(*program_id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // Collect the program ID of an external call or an external record cast.
+        match self {
+            Instruction::Call(call) => {
+                if let CallOperator::Locator(locator) = call.operator() {
+                    programs.push(*locator.program_id());
+                }
+            }
+            Instruction::Cast(cast) => {
+                if let CastType::ExternalRecord(locator) = cast.cast_type() {
+                    programs.push(*locator.program_id());
+                }
+            }
+            Instruction::CastLossy(cast) => {
+                if let CastType::ExternalRecord(locator) = cast.cast_type() {
+                    programs.push(*locator.program_id());
+                }
+            }
+            _ => (),
+        }
+        programs
+    }
+
     /// Returns `true` if the given name is a reserved opcode.
     #[inline]
     fn is_reserved_opcode(name: &str) -> bool {
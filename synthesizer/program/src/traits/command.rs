@@ -15,12 +15,14 @@
 use console::{
     network::Network,
     prelude::{FromBytes, Parser, ToBytes},
-    program::{Identifier, Register},
+    program::{Identifier, ProgramID, Register},
 };
 
 pub trait CommandTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     /// Returns the destination registers of the command.
     fn destinations(&self) -> Vec<Register<N>>;
+    /// Returns the IDs of the programs referenced by the command.
+    fn referenced_programs(&self) -> Vec<ProgramID<N>>;
     /// Returns the branch target, if the command is a branch command.
     fn branch_to(&self) -> Option<&Identifier<N>>;
     /// Returns the position name, if the command is a position command.
@@ -15,12 +15,14 @@
 use console::{
     network::Network,
     prelude::{FromBytes, Parser, ToBytes},
-    program::Register,
+    program::{ProgramID, Register},
 };
 
 pub trait InstructionTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     /// Returns the destination registers of the instruction.
     fn destinations(&self) -> Vec<Register<N>>;
+    /// Returns the IDs of the programs referenced by the instruction.
+    fn referenced_programs(&self) -> Vec<ProgramID<N>>;
     /// Returns `true` if the given name is a reserved opcode.
     fn is_reserved_opcode(name: &str) -> bool;
 }
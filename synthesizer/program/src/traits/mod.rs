@@ -21,5 +21,10 @@ pub use finalize_store::*;
 mod instruction;
 pub use instruction::*;
 
+#[cfg(feature = "async")]
+mod program_registry;
+#[cfg(feature = "async")]
+pub use program_registry::*;
+
 mod stack_and_registers;
 pub use stack_and_registers::*;
@@ -0,0 +1,23 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Import, Program};
+use console::{network::Network, prelude::Result};
+
+/// A registry of programs that are loaded asynchronously, e.g. from a remote node.
+#[async_trait(?Send)]
+pub trait AsyncProgramRegistry<N: Network> {
+    /// Returns the program for the given import, or `None` if the registry does not contain it.
+    async fn get(&self, import: &Import<N>) -> Result<Option<Program<N>>>;
+}
@@ -14,6 +14,7 @@
 
 use crate::{boxed::Box, vec::Vec};
 
+/// A pool of jobs that are executed in parallel on the rayon thread pool, or serially with the `serial` feature.
 pub struct ExecutionPool<'a, T> {
     jobs: Vec<Box<dyn 'a + FnOnce() -> T + Send>>,
 }
@@ -27,10 +28,25 @@ impl<'a, T> ExecutionPool<'a, T> {
         Self { jobs: Vec::with_capacity(cap) }
     }
 
+    /// Adds a job to the pool. As the job may run on another thread, it must be `Send`,
+    /// i.e. everything it captures must be `Send`, and everything it captures by reference must be `Sync`.
+    ///
+    /// A job that captures a value which is not `Send` is rejected at compile time:
+    /// ```compile_fail
+    /// use snarkvm_utilities::ExecutionPool;
+    /// use std::rc::Rc;
+    ///
+    /// let value = Rc::new(1u8);
+    /// let mut pool = ExecutionPool::new();
+    /// // error[E0277]: `Rc<u8>` cannot be sent between threads safely
+    /// pool.add_job(move || *value);
+    /// ```
     pub fn add_job<F: 'a + FnOnce() -> T + Send>(&mut self, f: F) {
         self.jobs.push(Box::new(f));
     }
 
+    /// Executes all jobs, and returns their outputs in the order in which the jobs were added.
+    /// The outputs are collected across threads, so they must be `Send` and `Sync`.
     pub fn execute_all(self) -> Vec<T>
     where
         T: Send + Sync,